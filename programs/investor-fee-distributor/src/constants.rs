@@ -7,6 +7,55 @@ pub const POLICY_CONFIG_SEED: &[u8] = b"policy_config";
 /// Seed for DailyProgress PDA
 pub const DAILY_PROGRESS_SEED: &[u8] = b"daily_progress";
 
+/// Seed for InvestorRegistryPage PDAs
+pub const INVESTOR_REGISTRY_SEED: &[u8] = b"investor_registry";
+
+/// Seed for InvestorLedger PDAs
+pub const INVESTOR_LEDGER_SEED: &[u8] = b"investor_ledger";
+
+/// Seed for DailyDistribution PDAs
+pub const DAILY_DISTRIBUTION_SEED: &[u8] = b"daily_distribution";
+
+/// Seed for MerkleClaimReceipt PDAs
+pub const MERKLE_CLAIM_SEED: &[u8] = b"merkle_claim";
+
+/// Minimum time investors get to claim a Merkle distribution
+pub const MIN_MERKLE_CLAIM_WINDOW: i64 = SECONDS_PER_DAY;
+
+/// Seed for the temporary token account used to unwrap wSOL payouts
+pub const NATIVE_UNWRAP_SEED: &[u8] = b"native_unwrap";
+
+/// Seed for the program-wide GuardianConfig PDA
+pub const GUARDIAN_CONFIG_SEED: &[u8] = b"guardian_config";
+
+/// Seed for WeightTable PDA
+pub const WEIGHT_TABLE_SEED: &[u8] = b"weight_table";
+
+/// Maximum investors in a static weight table
+pub const MAX_WEIGHT_ENTRIES: usize = 128;
+
+/// Maximum investors per registry page (and per crank transaction)
+pub const MAX_INVESTORS_PER_PAGE: usize = 20;
+
+/// Maximum pages per day (size of the DailyProgress page bitmaps)
+pub const MAX_PAGES_PER_DAY: usize = 256;
+
+/// Bytes needed for a bitmap of MAX_PAGES_PER_DAY pages
+pub const PAGE_BITMAP_BYTES: usize = MAX_PAGES_PER_DAY / 8;
+
+/// Maximum stream senders accepted by a policy's vesting filter
+pub const MAX_ALLOWED_SENDERS: usize = 8;
+
+/// Maximum vesting programs accepted by a policy
+pub const MAX_VESTING_PROGRAMS: usize = 4;
+
+/// Maximum missed days a catch-up run splits fees across (older fees fold
+/// into the oldest day)
+pub const MAX_CATCH_UP_DAYS: u64 = 7;
+
+/// Base amount a swap price floor is quoted against (quote atoms per 1e9 base atoms)
+pub const SWAP_PRICE_SCALE: u64 = 1_000_000_000;
+
 /// Seconds in a day (24 hours)
 pub const SECONDS_PER_DAY: i64 = 86400;
 
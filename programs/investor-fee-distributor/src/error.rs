@@ -52,4 +52,151 @@ pub enum ErrorCode {
 
     #[msg("Y0 total streamed amount cannot be zero")]
     InvalidY0Amount,
+
+    #[msg("Signer is not the policy authority")]
+    Unauthorized,
+
+    #[msg("Cannot update policy while a day's distribution is in progress")]
+    DistributionInProgress,
+
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthority,
+
+    #[msg("Signer is not the pending authority")]
+    InvalidPendingAuthority,
+
+    #[msg("Proposed authority is invalid")]
+    InvalidNewAuthority,
+
+    #[msg("Locked-total snapshot for today is not complete")]
+    SnapshotIncomplete,
+
+    #[msg("Locked-total snapshot for today is already complete")]
+    SnapshotAlreadyComplete,
+
+    #[msg("Page accounts do not match the investor registry")]
+    RegistryPageMismatch,
+
+    #[msg("Investor registry page index or size is invalid")]
+    InvalidRegistryPage,
+
+    #[msg("Duplicate stream in investor registry page")]
+    DuplicateRegistryEntry,
+
+    #[msg("Cannot modify investor registry while a day's distribution is in progress")]
+    RegistryLocked,
+
+    #[msg("Page has already been processed today")]
+    PageAlreadyProcessed,
+
+    #[msg("Pool account is not a valid pool for this AMM")]
+    InvalidPool,
+
+    #[msg("Payout account is not the stream recipient's quote token account")]
+    PayoutAccountMismatch,
+
+    #[msg("Vesting filter must name a mint and 1..=MAX_ALLOWED_SENDERS unique senders")]
+    InvalidVestingFilter,
+
+    #[msg("Vesting filter has not been set on the policy")]
+    VestingFilterNotSet,
+
+    #[msg("Stream does not vest the policy's vesting mint")]
+    StreamMintMismatch,
+
+    #[msg("Stream sender is not in the policy's allowed sender set")]
+    StreamSenderNotAllowed,
+
+    #[msg("Vesting account is not owned by an accepted vesting program")]
+    VestingProgramNotAccepted,
+
+    #[msg("Vesting account data does not match the expected layout")]
+    VestingAccountMismatch,
+
+    #[msg("Weight table account is required in static table weight mode")]
+    WeightTableRequired,
+
+    #[msg("Investor is not in the weight table")]
+    InvestorNotInWeightTable,
+
+    #[msg("Investor is already in the weight table")]
+    InvestorAlreadyInWeightTable,
+
+    #[msg("Weight table is full")]
+    WeightTableFull,
+
+    #[msg("Investor ledger does not belong to this vault and vesting account")]
+    InvalidInvestorLedger,
+
+    #[msg("Instruction is not available in the policy's distribution mode")]
+    WrongDistributionMode,
+
+    #[msg("Merkle distribution total does not equal the day's investor share")]
+    InvalidMerkleTotal,
+
+    #[msg("Merkle proof does not match the posted root")]
+    InvalidMerkleProof,
+
+    #[msg("Merkle claim window is too short")]
+    InvalidClaimWindow,
+
+    #[msg("Merkle distribution claim window has expired")]
+    ClaimWindowExpired,
+
+    #[msg("Merkle distribution claim window is still open")]
+    ClaimWindowOpen,
+
+    #[msg("Investor fee position owner is required to settle carried dust")]
+    PositionOwnerRequired,
+
+    #[msg("Native SOL payout accounts are required to pay lamports")]
+    NativeSolAccountsRequired,
+
+    #[msg("Destination is not the policy's base fee destination")]
+    InvalidBaseFeeDestination,
+
+    #[msg("Treasury holds no base fees to sweep")]
+    NoBaseFeesToSweep,
+
+    #[msg("Swap slippage exceeds 10000 basis points")]
+    InvalidSlippageBps,
+
+    #[msg("Pool curve does not support base fee swaps")]
+    UnsupportedSwapCurve,
+
+    #[msg("Protocol fee account is required to swap base fees")]
+    SwapAccountsRequired,
+
+    #[msg("Distributions are paused")]
+    DistributionPaused,
+
+    #[msg("Registry streams must be strictly ascending within and across pages")]
+    UnsortedRegistry,
+
+    #[msg("Policy config is not in the legacy layout")]
+    PolicyConfigNotLegacy,
+
+    #[msg("Base fee swaps require a minimum swap price")]
+    SwapPriceFloorRequired,
+
+    #[msg("Daily progress is not in the legacy layout")]
+    DailyProgressNotLegacy,
+
+    #[msg("Position owner is not in the legacy layout")]
+    PositionOwnerNotLegacy,
+
+    #[msg("Time range start must not be after its end")]
+    InvalidTimeRange,
+
+    #[msg("Merkle leaf amount exceeds its pro-rata share of the locked total")]
+    MerkleAmountExceedsShare,
+
+    #[msg("Locked total exceeds the total investor allocation")]
+    LockedTotalExceedsAllocation,
+
+    #[msg("Base fees must stay swapped or quarantined while the position accrues them")]
+    BaseFeeHandlingRequired,
+
+    #[msg("Quote mint, treasury and token program are required to pay removed investors' dust")]
+    DustPayoutAccountsRequired,
 }
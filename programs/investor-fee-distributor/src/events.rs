@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::state::{DistributionMode, VestingProgram, WeightMode};
 
 #[event]
 pub struct HonoraryPositionInitialized {
@@ -14,10 +15,23 @@ pub struct HonoraryPositionInitialized {
 pub struct QuoteFeesClaimed {
     pub day_id: u64,
     pub amount_claimed: u64,
+    pub day_fees: u64,
+    pub balance_before_claim: u64,
+    pub reserved_carry: u64,
     pub position: Pubkey,
     pub timestamp: i64,
 }
 
+#[event]
+pub struct LockedTotalSnapshotPage {
+    pub day_id: u64,
+    pub page: u16,
+    pub page_locked: u64,
+    pub locked_total: u64,
+    pub is_complete: bool,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct InvestorPayoutPage {
     pub day_id: u64,
@@ -25,6 +39,18 @@ pub struct InvestorPayoutPage {
     pub investors_paid: u16,
     pub total_distributed: u64,
     pub dust_carried: u64,
+    pub transfer_fees: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CarriedDustPaid {
+    pub vault: Pubkey,
+    pub day_id: u64,
+    pub stream: Pubkey,
+    pub payout_account: Pubkey,
+    pub carried_amount: u64,
+    pub total_paid: u64,
     pub timestamp: i64,
 }
 
@@ -34,6 +60,7 @@ pub struct CreatorPayoutDayClosed {
     pub creator_amount: u64,
     pub total_investors_paid: u64,
     pub total_pages: u16,
+    pub rolled_over: u64,
     pub timestamp: i64,
 }
 
@@ -46,6 +73,75 @@ pub struct PolicyConfigCreated {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct PolicyConfigMigrated {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub old_space: u64,
+    pub new_space: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PolicyConfigUpdated {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub old_investor_fee_share_bps: u16,
+    pub new_investor_fee_share_bps: u16,
+    pub old_daily_cap_lamports: Option<u64>,
+    pub new_daily_cap_lamports: Option<u64>,
+    pub old_min_payout_lamports: u64,
+    pub new_min_payout_lamports: u64,
+    pub old_y0_total_streamed: u64,
+    pub new_y0_total_streamed: u64,
+    pub old_creator_quote_ata: Pubkey,
+    pub new_creator_quote_ata: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferAccepted {
+    pub vault: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub cancelled_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InvestorRegistryPageSet {
+    pub vault: Pubkey,
+    pub page_index: u16,
+    pub num_entries: u16,
+    pub registry_pages: u16,
+    pub dust_released: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InvestorRegistryPageClosed {
+    pub vault: Pubkey,
+    pub page_index: u16,
+    pub registry_pages: u16,
+    pub dust_released: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct DailyProgressReset {
     pub vault: Pubkey,
@@ -53,3 +149,201 @@ pub struct DailyProgressReset {
     pub new_day_id: u64,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct VestingFilterSet {
+    pub vault: Pubkey,
+    pub vesting_mint: Pubkey,
+    pub allowed_senders: Vec<Pubkey>,
+    pub vesting_programs: Vec<VestingProgram>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WeightModeSet {
+    pub vault: Pubkey,
+    pub old_weight_mode: WeightMode,
+    pub new_weight_mode: WeightMode,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WeightEntryChanged {
+    pub vault: Pubkey,
+    pub investor: Pubkey,
+    pub old_weight: u64,
+    pub new_weight: u64,
+    pub total_weight: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DistributionModeSet {
+    pub vault: Pubkey,
+    pub old_distribution_mode: DistributionMode,
+    pub new_distribution_mode: DistributionMode,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RollOverUndistributedSet {
+    pub vault: Pubkey,
+    pub roll_over_undistributed: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CatchUpMissedDaysSet {
+    pub vault: Pubkey,
+    pub catch_up_missed_days: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NativeSolPayoutsSet {
+    pub vault: Pubkey,
+    pub pay_native_sol: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BaseFeeHandlingSet {
+    pub vault: Pubkey,
+    pub base_fee_destination: Pubkey,
+    pub quarantine_base_fees: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BaseFeesQuarantined {
+    pub vault: Pubkey,
+    pub day_id: u64,
+    pub base_mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BaseFeesSwept {
+    pub vault: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BaseFeeSwapSet {
+    pub vault: Pubkey,
+    pub swap_base_fees: bool,
+    pub max_swap_slippage_bps: u16,
+    pub min_base_swap_amount: u64,
+    pub min_swap_price: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BaseFeesSwapped {
+    pub vault: Pubkey,
+    pub day_id: u64,
+    pub base_amount: u64,
+    pub quote_received: u64,
+    pub minimum_out: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DistributionPauseSet {
+    pub vault: Pubkey,
+    pub paused: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GuardianSet {
+    pub guardian: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GlobalPauseSet {
+    pub guardian: Pubkey,
+    pub paused: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InvestorLedgerOpened {
+    pub vault: Pubkey,
+    pub ledger: Pubkey,
+    pub vesting_account: Pubkey,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ClaimDelegateSet {
+    pub ledger: Pubkey,
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InvestorFeesClaimed {
+    pub vault: Pubkey,
+    pub ledger: Pubkey,
+    pub claimant: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MerkleRootPosted {
+    pub vault: Pubkey,
+    pub day_id: u64,
+    pub merkle_root: [u8; 32],
+    pub locked_total: u64,
+    pub total_amount: u64,
+    pub creator_amount: u64,
+    pub num_leaves: u32,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MerkleFeesClaimed {
+    pub vault: Pubkey,
+    pub day_id: u64,
+    pub index: u32,
+    pub investor: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MerkleDistributionReclaimed {
+    pub vault: Pubkey,
+    pub day_id: u64,
+    pub creator_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DailyProgressMigrated {
+    pub vault: Pubkey,
+    pub day_id: u64,
+    pub old_space: u64,
+    pub new_space: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PositionOwnerMigrated {
+    pub vault: Pubkey,
+    pub position: Pubkey,
+    pub old_space: u64,
+    pub new_space: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::ErrorCode, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [POLICY_CONFIG_SEED, policy_config.vault.as_ref()],
+        bump = policy_config.bump,
+        constraint = policy_config.has_pending_authority() @ ErrorCode::NoPendingAuthority,
+        constraint = policy_config.pending_authority == new_authority.key() @ ErrorCode::InvalidPendingAuthority,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    pub new_authority: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let policy = &mut ctx.accounts.policy_config;
+    let clock = Clock::get()?;
+
+    let old_authority = policy.authority;
+    policy.authority = ctx.accounts.new_authority.key();
+    policy.pending_authority = Pubkey::default();
+
+    emit!(AuthorityTransferAccepted {
+        vault: policy.vault,
+        old_authority,
+        new_authority: policy.authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
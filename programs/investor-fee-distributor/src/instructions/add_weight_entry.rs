@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::ErrorCode, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct AddWeightEntry<'info> {
+    #[account(
+        seeds = [POLICY_CONFIG_SEED, policy_config.vault.as_ref()],
+        bump = policy_config.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    /// Static investor weights
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + WeightTable::INIT_SPACE,
+        seeds = [WEIGHT_TABLE_SEED, policy_config.vault.as_ref()],
+        bump
+    )]
+    pub weight_table: Account<'info, WeightTable>,
+
+    /// CHECK: Daily progress PDA (may not exist before the first crank) - read for the in-progress guard
+    #[account(seeds = [DAILY_PROGRESS_SEED, policy_config.vault.as_ref()], bump)]
+    pub daily_progress: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Add an investor to the static weight table (created on first use)
+pub(crate) fn handler(ctx: Context<AddWeightEntry>, investor: Pubkey, weight: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let day_id = (clock.unix_timestamp / SECONDS_PER_DAY) as u64;
+
+    // Refuse changes while today's pages are being processed
+    DailyProgress::require_no_day_in_progress(
+        &ctx.accounts.daily_progress,
+        day_id,
+        ErrorCode::RegistryLocked,
+    )?;
+
+    let vault = ctx.accounts.policy_config.vault;
+    let table = &mut ctx.accounts.weight_table;
+    table.bump = ctx.bumps.weight_table;
+    table.vault = vault;
+    table.add(investor, weight)?;
+
+    emit!(WeightEntryChanged {
+        vault,
+        investor,
+        old_weight: 0,
+        new_weight: weight,
+        total_weight: table.total_weight,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::ErrorCode, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [POLICY_CONFIG_SEED, policy_config.vault.as_ref()],
+        bump = policy_config.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+        constraint = policy_config.has_pending_authority() @ ErrorCode::NoPendingAuthority,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+    let policy = &mut ctx.accounts.policy_config;
+    let clock = Clock::get()?;
+
+    let cancelled_authority = policy.pending_authority;
+    policy.pending_authority = Pubkey::default();
+
+    emit!(AuthorityTransferCancelled {
+        vault: policy.vault,
+        authority: policy.authority,
+        cancelled_authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
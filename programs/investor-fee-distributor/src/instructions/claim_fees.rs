@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::{constants::*, error::ErrorCode, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct ClaimFees<'info> {
+    /// Ledger owner or its delegate
+    pub claimant: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            INVESTOR_LEDGER_SEED,
+            investor_ledger.vault.as_ref(),
+            investor_ledger.vesting_account.as_ref(),
+        ],
+        bump = investor_ledger.bump,
+        constraint = investor_ledger.is_claimant(&claimant.key()) @ ErrorCode::Unauthorized,
+    )]
+    pub investor_ledger: Account<'info, InvestorLedger>,
+
+    /// Honorary position owner PDA (treasury authority)
+    #[account(
+        mut,
+        seeds = [INVESTOR_FEE_POS_OWNER_SEED, investor_ledger.vault.as_ref()],
+        bump = investor_fee_position_owner.bump,
+    )]
+    pub investor_fee_position_owner: Account<'info, InvestorFeePositionOwner>,
+
+    /// Quote mint (SPL Token or Token-2022)
+    #[account(
+        address = investor_fee_position_owner.quote_mint @ ErrorCode::InvalidTokenMint,
+        mint::token_program = token_program,
+    )]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
+
+    /// Treasury quote token account (owned by investor_fee_position_owner)
+    #[account(
+        mut,
+        constraint = treasury_quote_ata.mint == investor_fee_position_owner.quote_mint @ ErrorCode::InvalidTokenMint,
+        constraint = treasury_quote_ata.owner == investor_fee_position_owner.key() @ ErrorCode::InvalidPosition,
+    )]
+    pub treasury_quote_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Destination quote account; delegates may only claim to the owner's accounts
+    #[account(
+        mut,
+        constraint = destination.mint == investor_fee_position_owner.quote_mint @ ErrorCode::InvalidTokenMint,
+        constraint = claimant.key() == investor_ledger.owner
+            || destination.owner == investor_ledger.owner @ ErrorCode::PayoutAccountMismatch,
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Withdraw everything credited to an investor ledger
+pub(crate) fn handler(ctx: Context<ClaimFees>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let amount = ctx.accounts.investor_ledger.claimable();
+    require!(amount > 0, ErrorCode::NoFeesAvailable);
+
+    let position_owner = &ctx.accounts.investor_fee_position_owner;
+    let seeds = &[
+        INVESTOR_FEE_POS_OWNER_SEED,
+        position_owner.vault.as_ref(),
+        &[position_owner.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.treasury_quote_ata.to_account_info(),
+                mint: ctx.accounts.quote_mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: position_owner.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        ctx.accounts.quote_mint.decimals,
+    )?;
+
+    let position_owner = &mut ctx.accounts.investor_fee_position_owner;
+    position_owner.pending_claims = position_owner.pending_claims
+        .checked_sub(amount)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+    let ledger = &mut ctx.accounts.investor_ledger;
+    ledger.claimed = ledger.claimed
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(InvestorFeesClaimed {
+        vault: ledger.vault,
+        ledger: ledger.key(),
+        claimant: ctx.accounts.claimant.key(),
+        destination: ctx.accounts.destination.key(),
+        amount,
+        total_claimed: ledger.claimed,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
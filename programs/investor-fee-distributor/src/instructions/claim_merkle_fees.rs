@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::{constants::*, error::ErrorCode, events::*, state::*, utils::*};
+
+#[derive(Accounts)]
+#[instruction(index: u32)]
+pub struct ClaimMerkleFees<'info> {
+    /// Investor named in the leaf
+    #[account(mut)]
+    pub investor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            DAILY_DISTRIBUTION_SEED,
+            daily_distribution.vault.as_ref(),
+            &daily_distribution.day_id.to_le_bytes(),
+        ],
+        bump = daily_distribution.bump,
+    )]
+    pub daily_distribution: Account<'info, DailyDistribution>,
+
+    /// Created on claim; a second claim of the same leaf fails here
+    #[account(
+        init,
+        payer = investor,
+        space = 8 + MerkleClaimReceipt::INIT_SPACE,
+        seeds = [
+            MERKLE_CLAIM_SEED,
+            daily_distribution.key().as_ref(),
+            &index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub claim_receipt: Account<'info, MerkleClaimReceipt>,
+
+    /// Honorary position owner PDA (treasury authority)
+    #[account(
+        mut,
+        seeds = [INVESTOR_FEE_POS_OWNER_SEED, daily_distribution.vault.as_ref()],
+        bump = investor_fee_position_owner.bump,
+    )]
+    pub investor_fee_position_owner: Account<'info, InvestorFeePositionOwner>,
+
+    /// Quote mint (SPL Token or Token-2022)
+    #[account(
+        address = investor_fee_position_owner.quote_mint @ ErrorCode::InvalidTokenMint,
+        mint::token_program = token_program,
+    )]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
+
+    /// Treasury quote token account (owned by investor_fee_position_owner)
+    #[account(
+        mut,
+        constraint = treasury_quote_ata.mint == investor_fee_position_owner.quote_mint @ ErrorCode::InvalidTokenMint,
+        constraint = treasury_quote_ata.owner == investor_fee_position_owner.key() @ ErrorCode::InvalidPosition,
+    )]
+    pub treasury_quote_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Investor's quote token account
+    #[account(
+        mut,
+        constraint = destination.mint == investor_fee_position_owner.quote_mint @ ErrorCode::InvalidTokenMint,
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Claim one leaf of a Merkle distribution with its proof
+pub(crate) fn handler(
+    ctx: Context<ClaimMerkleFees>,
+    index: u32,
+    amount: u64,
+    locked: u64,
+    proof: Vec<MerkleProofNode>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let distribution = &ctx.accounts.daily_distribution;
+
+    require!(
+        distribution.is_claimable(clock.unix_timestamp),
+        ErrorCode::ClaimWindowExpired
+    );
+    require!(index < distribution.num_leaves, ErrorCode::InvalidMerkleProof);
+
+    let investor = ctx.accounts.investor.key();
+    let leaf = merkle_leaf(index, &investor, amount, locked);
+    require!(
+        verify_merkle_proof(
+            &proof,
+            &distribution.merkle_root,
+            distribution.locked_total,
+            leaf,
+            locked,
+        ),
+        ErrorCode::InvalidMerkleProof
+    );
+
+    // No leaf may pay more than its pro-rata part of the committed locked total
+    require!(
+        amount <= distribution.max_leaf_amount(locked)?,
+        ErrorCode::MerkleAmountExceedsShare
+    );
+
+    let position_owner = &ctx.accounts.investor_fee_position_owner;
+    let seeds = &[
+        INVESTOR_FEE_POS_OWNER_SEED,
+        position_owner.vault.as_ref(),
+        &[position_owner.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.treasury_quote_ata.to_account_info(),
+                mint: ctx.accounts.quote_mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: position_owner.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        ctx.accounts.quote_mint.decimals,
+    )?;
+
+    let distribution = &mut ctx.accounts.daily_distribution;
+    distribution.claimed_amount = distribution.claimed_amount
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    // A root whose leaves overspend the posted total is rejected here
+    require!(
+        distribution.claimed_amount <= distribution.total_amount,
+        ErrorCode::InvalidMerkleTotal
+    );
+
+    let position_owner = &mut ctx.accounts.investor_fee_position_owner;
+    position_owner.pending_claims = position_owner.pending_claims
+        .checked_sub(amount)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+    let receipt = &mut ctx.accounts.claim_receipt;
+    receipt.bump = ctx.bumps.claim_receipt;
+    receipt.investor = investor;
+    receipt.amount = amount;
+
+    emit!(MerkleFeesClaimed {
+        vault: distribution.vault,
+        day_id: distribution.day_id,
+        index,
+        investor,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::{constants::*, error::ErrorCode, events::*, state::*};
+use super::set_investor_registry_page::pay_removed_dust;
+
+#[derive(Accounts)]
+pub struct CloseInvestorRegistryPage<'info> {
+    #[account(
+        mut,
+        seeds = [POLICY_CONFIG_SEED, policy_config.vault.as_ref()],
+        bump = policy_config.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    /// Only the last page may be closed so pages stay contiguous
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            INVESTOR_REGISTRY_SEED,
+            policy_config.vault.as_ref(),
+            &investor_registry_page.page_index.to_le_bytes(),
+        ],
+        bump = investor_registry_page.bump,
+        constraint = investor_registry_page.page_index + 1 == policy_config.registry_pages @ ErrorCode::InvalidRegistryPage,
+    )]
+    pub investor_registry_page: Account<'info, InvestorRegistryPage>,
+
+    /// CHECK: Daily progress PDA (may not exist before the first crank) - read for the in-progress guard
+    #[account(seeds = [DAILY_PROGRESS_SEED, policy_config.vault.as_ref()], bump)]
+    pub daily_progress: UncheckedAccount<'info>,
+
+    /// Honorary position owner (required only when removed investors carry dust)
+    #[account(
+        mut,
+        seeds = [INVESTOR_FEE_POS_OWNER_SEED, policy_config.vault.as_ref()],
+        bump = investor_fee_position_owner.bump,
+    )]
+    pub investor_fee_position_owner: Option<Account<'info, InvestorFeePositionOwner>>,
+
+    /// Quote mint (required only when removed investors carry dust)
+    pub quote_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Treasury quote token account (required only when removed investors carry dust)
+    #[account(mut)]
+    pub treasury_quote_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/// Close the last registry page, paying its investors any dust carried for them
+/// (their registered quote ATAs are passed as remaining accounts, in page order)
+pub(crate) fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseInvestorRegistryPage<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let day_id = (clock.unix_timestamp / SECONDS_PER_DAY) as u64;
+
+    // Refuse changes while today's pages are being processed
+    DailyProgress::require_no_day_in_progress(
+        &ctx.accounts.daily_progress,
+        day_id,
+        ErrorCode::RegistryLocked,
+    )?;
+
+    let page_index = ctx.accounts.investor_registry_page.page_index;
+    let removed = ctx.accounts.investor_registry_page.carried_entries();
+
+    // Removed investors keep the dust they accrued
+    let dust_released = pay_removed_dust(
+        &removed,
+        ctx.remaining_accounts,
+        ctx.accounts.investor_fee_position_owner.as_mut(),
+        ctx.accounts.quote_mint.as_ref(),
+        ctx.accounts.treasury_quote_ata.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        clock.unix_timestamp,
+    )?;
+
+    let policy = &mut ctx.accounts.policy_config;
+    policy.registry_pages = page_index;
+
+    emit!(InvestorRegistryPageClosed {
+        vault: policy.vault,
+        page_index,
+        registry_pages: policy.registry_pages,
+        dust_released,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,259 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::{constants::*, error::ErrorCode, state::*, cp_amm};
+use super::{
+    crank_distribution::InvestorData,
+    distribution::{NativeSolAccounts, PageDistribution},
+};
+
+#[derive(Accounts)]
+pub struct CrankDistributionCpAmm<'info> {
+    /// Cranker (permissionless - anyone can call)
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    /// Daily progress tracking account (created by snapshot_locked_total)
+    #[account(
+        mut,
+        seeds = [DAILY_PROGRESS_SEED, investor_fee_position_owner.vault.as_ref()],
+        bump = daily_progress.bump,
+    )]
+    pub daily_progress: Account<'info, DailyProgress>,
+
+    /// Policy configuration
+    #[account(
+        seeds = [POLICY_CONFIG_SEED, investor_fee_position_owner.vault.as_ref()],
+        bump = policy_config.bump,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    /// CHECK: Program-wide guardian config PDA (may not exist yet) - read for the global pause
+    #[account(seeds = [GUARDIAN_CONFIG_SEED], bump)]
+    pub guardian_config: UncheckedAccount<'info>,
+
+    /// Honorary position owner PDA (cp-amm position NFT)
+    #[account(
+        mut,
+        seeds = [INVESTOR_FEE_POS_OWNER_SEED, investor_fee_position_owner.vault.as_ref()],
+        bump = investor_fee_position_owner.bump,
+        constraint = investor_fee_position_owner.position_kind == PositionKind::CpAmmPosition @ ErrorCode::InvalidPosition,
+    )]
+    pub investor_fee_position_owner: Account<'info, InvestorFeePositionOwner>,
+
+    /// Registry page being paid (any order, once per day; holds carried dust)
+    #[account(
+        mut,
+        seeds = [
+            INVESTOR_REGISTRY_SEED,
+            investor_fee_position_owner.vault.as_ref(),
+            &investor_registry_page.page_index.to_le_bytes(),
+        ],
+        bump = investor_registry_page.bump,
+    )]
+    pub investor_registry_page: Account<'info, InvestorRegistryPage>,
+
+    /// Static investor weights (required in static table weight mode)
+    #[account(
+        seeds = [WEIGHT_TABLE_SEED, investor_fee_position_owner.vault.as_ref()],
+        bump = weight_table.bump,
+    )]
+    pub weight_table: Option<Account<'info, WeightTable>>,
+
+    // ===== Accounts for claim_position_fee CPI =====
+
+    /// CHECK: cp-amm pool authority - validated by cp-amm
+    pub pool_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Pool account - validated by cp-amm
+    #[account(address = investor_fee_position_owner.pool @ ErrorCode::InvalidPosition)]
+    pub pool: UncheckedAccount<'info>,
+
+    /// CHECK: Honorary position - validated by cp-amm
+    #[account(mut, address = investor_fee_position_owner.position @ ErrorCode::InvalidPosition)]
+    pub position: UncheckedAccount<'info>,
+
+    /// CHECK: Pool token A vault - validated by cp-amm
+    #[account(mut)]
+    pub token_a_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Pool token B vault - validated by cp-amm
+    #[account(mut)]
+    pub token_b_vault: UncheckedAccount<'info>,
+
+    /// Token A mint (base; SPL Token or Token-2022)
+    #[account(
+        address = investor_fee_position_owner.base_mint @ ErrorCode::InvalidTokenMint,
+        mint::token_program = base_token_program,
+    )]
+    pub token_a_mint: InterfaceAccount<'info, Mint>,
+
+    /// Token B mint (quote; SPL Token or Token-2022)
+    #[account(
+        address = investor_fee_position_owner.quote_mint @ ErrorCode::InvalidTokenMint,
+        mint::token_program = token_program,
+    )]
+    pub token_b_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Position NFT token account held by the PDA - validated by cp-amm
+    pub position_nft_account: UncheckedAccount<'info>,
+
+    /// CHECK: cp-amm event authority - validated by cp-amm
+    pub event_authority: UncheckedAccount<'info>,
+
+    /// CHECK: cp-amm (DAMM v2) program
+    #[account(address = cp_amm::ID)]
+    pub cp_amm_program: UncheckedAccount<'info>,
+
+    // ===== Treasury and distribution accounts =====
+
+    /// Treasury quote token account (owned by investor_fee_position_owner)
+    /// This receives token B fees from claim_position_fee CPI
+    #[account(
+        mut,
+        constraint = treasury_quote_ata.mint == investor_fee_position_owner.quote_mint @ ErrorCode::InvalidTokenMint,
+        constraint = treasury_quote_ata.owner == investor_fee_position_owner.key() @ ErrorCode::InvalidPosition,
+    )]
+    pub treasury_quote_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Treasury base token account (should remain empty - for validation)
+    /// This would receive token A fees if any (which we reject)
+    #[account(
+        mut,
+        constraint = treasury_base_ata.mint == investor_fee_position_owner.base_mint @ ErrorCode::InvalidTokenMint,
+        constraint = treasury_base_ata.owner == investor_fee_position_owner.key() @ ErrorCode::InvalidPosition,
+    )]
+    pub treasury_base_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Creator's quote token ATA (for remainder)
+    #[account(
+        mut,
+        constraint = creator_quote_ata.key() == policy_config.creator_quote_ata @ ErrorCode::InvalidPolicy,
+        constraint = creator_quote_ata.mint == investor_fee_position_owner.quote_mint @ ErrorCode::InvalidTokenMint,
+    )]
+    pub creator_quote_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Temporary wSOL account (native SOL option only) - created and closed by the crank
+    #[account(
+        mut,
+        seeds = [NATIVE_UNWRAP_SEED, investor_fee_position_owner.vault.as_ref()],
+        bump,
+    )]
+    pub native_unwrap_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Creator wallet receiving the remainder as lamports (native SOL option only)
+    #[account(mut, address = creator_quote_ata.owner @ ErrorCode::InvalidPolicy)]
+    pub creator_wallet: Option<UncheckedAccount<'info>>,
+
+    /// Quote token program
+    pub token_program: Interface<'info, TokenInterface>,
+    /// Base token program (may differ from the quote token program)
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+
+    // Remaining accounts:
+    // 1. Streamflow stream accounts (read-only) - for reading locked amounts
+    // 2. Investor quote ATAs (writable) - for transferring fees
+    //    (recipient wallets instead when paying native SOL)
+    // Both must match the registry page entries, in order
+    // Pattern: [stream_0, stream_1, ..., stream_n, ata_0, ata_1, ..., ata_n]
+}
+
+pub(crate) fn handler_cp_amm<'info>(
+    ctx: Context<'_, '_, '_, 'info, CrankDistributionCpAmm<'info>>,
+    investor_data: Vec<InvestorData>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let day_id = (current_time / SECONDS_PER_DAY) as u64;
+
+    // Validate inputs
+    require!(!investor_data.is_empty(), ErrorCode::InvalidInvestorPage);
+    ctx.accounts.policy_config.require_not_paused(&ctx.accounts.guardian_config)?;
+
+    // ===== STEP 1: REQUIRE TODAY'S LOCKED-TOTAL SNAPSHOT =====
+
+    ctx.accounts.daily_progress.require_payout_ready(day_id, current_time)?;
+    // The day being paid (earlier than today during a catch-up run)
+    let day_id = ctx.accounts.daily_progress.day_id;
+    let is_first_page = ctx.accounts.daily_progress.current_page == 0;
+
+    // ===== STEP 2: CLAIM FEES (First processed page only) =====
+
+    // Leftover treasury balance is not part of today's claim
+    let balance_before_claim = ctx.accounts.treasury_quote_ata.amount;
+
+    if is_first_page {
+        msg!("Claiming position fees via CPI to cp-amm");
+
+        let position_owner = &ctx.accounts.investor_fee_position_owner;
+        let seeds = &[
+            INVESTOR_FEE_POS_OWNER_SEED,
+            position_owner.vault.as_ref(),
+            &[position_owner.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = cp_amm::cpi::accounts::ClaimPositionFee {
+            pool_authority: ctx.accounts.pool_authority.to_account_info(),
+            pool: ctx.accounts.pool.to_account_info(),
+            position: ctx.accounts.position.to_account_info(),
+            token_a_account: ctx.accounts.treasury_base_ata.to_account_info(),
+            token_b_account: ctx.accounts.treasury_quote_ata.to_account_info(),
+            token_a_vault: ctx.accounts.token_a_vault.to_account_info(),
+            token_b_vault: ctx.accounts.token_b_vault.to_account_info(),
+            token_a_mint: ctx.accounts.token_a_mint.to_account_info(),
+            token_b_mint: ctx.accounts.token_b_mint.to_account_info(),
+            position_nft_account: ctx.accounts.position_nft_account.to_account_info(),
+            owner: position_owner.to_account_info(),
+            token_a_program: ctx.accounts.base_token_program.to_account_info(),
+            token_b_program: ctx.accounts.token_program.to_account_info(),
+            event_authority: ctx.accounts.event_authority.to_account_info(),
+            program: ctx.accounts.cp_amm_program.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.cp_amm_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+
+        cp_amm::cpi::claim_position_fee(cpi_ctx)?;
+
+        msg!("Fees claimed successfully");
+
+        // Refresh treasury balances after the CPI
+        ctx.accounts.treasury_quote_ata.reload()?;
+        ctx.accounts.treasury_base_ata.reload()?;
+    }
+
+    let accounts = &mut *ctx.accounts;
+    let mut page = PageDistribution {
+        progress: &mut accounts.daily_progress,
+        policy: &accounts.policy_config,
+        position_owner: &mut accounts.investor_fee_position_owner,
+        registry_page: &mut accounts.investor_registry_page,
+        weight_table: accounts.weight_table.as_ref(),
+        treasury_quote_ata: &accounts.treasury_quote_ata,
+        treasury_base_ata: &accounts.treasury_base_ata,
+        creator_quote_ata: &accounts.creator_quote_ata,
+        quote_mint: &accounts.token_b_mint,
+        token_program: &accounts.token_program,
+        cranker: &accounts.cranker,
+        system_program: &accounts.system_program,
+        native_sol: NativeSolAccounts::from_optional(
+            accounts.native_unwrap_account.as_ref(),
+            ctx.bumps.native_unwrap_account,
+            accounts.creator_wallet.as_ref(),
+        ),
+        remaining_accounts: ctx.remaining_accounts,
+    };
+
+    if is_first_page {
+        // Record the claim delta
+        page.record_claimed_fees(day_id, current_time, Some(balance_before_claim))?;
+    }
+
+    // ===== STEPS 3-8: DISTRIBUTE PAGE & FINALIZE =====
+
+    page.distribute_page(&investor_data, day_id, current_time)
+}
@@ -0,0 +1,623 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+use anchor_spl::token::spl_token::{self, native_mint};
+use anchor_spl::token_interface::{
+    self, CloseAccount, InitializeAccount3, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+use crate::{constants::*, error::ErrorCode, events::*, state::*, utils::*};
+use super::crank_distribution::InvestorData;
+
+/// Accounts shared by every crank variant once the day's fees sit in the treasury
+pub struct PageDistribution<'a, 'info> {
+    pub progress: &'a mut Account<'info, DailyProgress>,
+    pub policy: &'a Account<'info, PolicyConfig>,
+    pub position_owner: &'a mut Account<'info, InvestorFeePositionOwner>,
+    pub registry_page: &'a mut Account<'info, InvestorRegistryPage>,
+    pub weight_table: Option<&'a Account<'info, WeightTable>>,
+    pub treasury_quote_ata: &'a InterfaceAccount<'info, TokenAccount>,
+    pub treasury_base_ata: &'a InterfaceAccount<'info, TokenAccount>,
+    pub creator_quote_ata: &'a InterfaceAccount<'info, TokenAccount>,
+    pub quote_mint: &'a InterfaceAccount<'info, Mint>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+    pub cranker: &'a Signer<'info>,
+    pub system_program: &'a Program<'info, System>,
+    /// Accounts for lamport payouts (only needed with the native SOL option)
+    pub native_sol: Option<NativeSolAccounts<'a, 'info>>,
+    /// Pattern: [stream_0, ..., stream_n, ata_0, ..., ata_n]
+    /// (ledger_i instead of ata_i in pull distribution mode)
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+/// Accounts used to unwrap wSOL and pay lamports under the native SOL option
+pub struct NativeSolAccounts<'a, 'info> {
+    /// Temporary wSOL account PDA, created and closed within each crank
+    pub unwrap_account: &'a UncheckedAccount<'info>,
+    pub unwrap_bump: u8,
+    /// Wallet owning the creator's quote ATA (receives the remainder)
+    pub creator_wallet: &'a UncheckedAccount<'info>,
+}
+
+impl<'a, 'info> NativeSolAccounts<'a, 'info> {
+    /// Bundle a crank's optional native SOL accounts (None unless all are passed)
+    pub fn from_optional(
+        unwrap_account: Option<&'a UncheckedAccount<'info>>,
+        unwrap_bump: Option<u8>,
+        creator_wallet: Option<&'a UncheckedAccount<'info>>,
+    ) -> Option<Self> {
+        Some(Self {
+            unwrap_account: unwrap_account?,
+            unwrap_bump: unwrap_bump?,
+            creator_wallet: creator_wallet?,
+        })
+    }
+}
+
+impl<'a, 'info> PageDistribution<'a, 'info> {
+    /// Record the day's claimed fees and fix the investor share (first processed page only)
+    /// `balance_before_claim` is the treasury balance ahead of a claim CPI, if any
+    pub fn record_claimed_fees(
+        &mut self,
+        day_id: u64,
+        current_time: i64,
+        balance_before_claim: Option<u64>,
+    ) -> Result<()> {
+        let progress = &mut *self.progress;
+        let policy = self.policy;
+
+        // Treasury quote that belongs to earlier days
+        let reserved_carry = progress.reserved_carry(self.position_owner.owed_to_investors()?)?;
+
+        // Only the claim delta is today's pool; without a claim CPI, whatever
+        // exceeds the reserved carry was deposited as fees
+        let balance_after = self.treasury_quote_ata.amount;
+        let balance_before = balance_before_claim
+            .unwrap_or(reserved_carry)
+            .min(balance_after);
+        let amount_claimed = self.position_owner.record_fee_claim(
+            balance_before,
+            balance_after,
+            reserved_carry,
+            current_time,
+        )?;
+
+        // Fees accrued over missed days are split evenly across the catch-up run
+        let quote_fees = progress.open_day_pool(amount_claimed)?;
+
+        emit!(QuoteFeesClaimed {
+            day_id,
+            amount_claimed,
+            day_fees: quote_fees,
+            balance_before_claim: balance_before,
+            reserved_carry,
+            position: self.position_owner.position,
+            timestamp: current_time,
+        });
+
+        // Base fees are left in the treasury for sweep_base_fees, reported once per day
+        let base_balance = self.treasury_base_ata.amount;
+        if base_balance > 0 && policy.quarantine_base_fees {
+            emit!(BaseFeesQuarantined {
+                vault: self.position_owner.vault,
+                day_id,
+                base_mint: self.position_owner.base_mint,
+                amount: base_balance,
+                timestamp: current_time,
+            });
+        }
+
+        // Fix the investor share once for the whole day
+        let investor_share = policy.investor_share(quote_fees, progress.locked_total_snapshot)?;
+        progress.fix_investor_fee_quote(
+            investor_share,
+            policy.daily_cap_lamports,
+            policy.roll_over_undistributed,
+        )
+    }
+
+    /// Pay one registry page pro-rata and finalize the day after the last page
+    pub fn distribute_page(
+        &mut self,
+        investor_data: &[InvestorData],
+        day_id: u64,
+        current_time: i64,
+    ) -> Result<()> {
+        // Merkle distributions are posted by the authority, not paged
+        require!(
+            self.policy.distribution_mode != DistributionMode::Merkle,
+            ErrorCode::WrongDistributionMode
+        );
+
+        // wSOL push payouts and the creator remainder go out as lamports
+        let pay_native_sol = self.pays_native_sol();
+        require!(
+            !pay_native_sol || self.native_sol.is_some(),
+            ErrorCode::NativeSolAccountsRequired
+        );
+        let mut native_payouts: Vec<(AccountInfo<'info>, u64)> = Vec::new();
+
+        // ===== STEP 3: VALIDATE QUOTE-ONLY =====
+
+        // Quarantined base fees stay in the treasury base ATA and are never paid out
+        let base_balance = self.treasury_base_ata.amount;
+        require!(
+            base_balance == 0 || self.policy.quarantine_base_fees,
+            ErrorCode::BaseFeesDetected
+        );
+
+        // ===== STEP 4: LOAD LOCKED-TOTAL SNAPSHOT =====
+
+        let num_investors = investor_data.len();
+
+        // Remaining accounts split: first half are stream accounts, second half are
+        // payout ATAs (push mode) or investor ledgers (pull mode)
+        require!(
+            self.remaining_accounts.len() == num_investors * 2,
+            ErrorCode::InvalidInvestorPage
+        );
+
+        // Page accounts must match the registry entries for this page index
+        let registry_page = &*self.registry_page;
+        let page_index = registry_page.page_index;
+        let distribution_mode = self.policy.distribution_mode;
+        registry_page.verify_streams(&self.remaining_accounts[0..num_investors])?;
+        if distribution_mode == DistributionMode::Push && !pay_native_sol {
+            registry_page.verify_payout_accounts(&self.remaining_accounts[num_investors..])?;
+        }
+        for (investor, entry) in investor_data.iter().zip(registry_page.entries.iter()) {
+            require_keys_eq!(investor.quote_ata, entry.quote_ata, ErrorCode::RegistryPageMismatch);
+        }
+
+        // Pages may be paid in any order, but each exactly once
+        self.progress.mark_payout_page(page_index)?;
+
+        let locked_total = self.progress.locked_total_snapshot;
+        let snapshot_ts = self.progress.snapshot_ts;
+
+        // If nothing is locked, all fees go to creator
+        if locked_total == 0 {
+            msg!("No tokens locked - all fees will go to creator");
+
+            // Skip to finalization
+            self.progress.mark_all_pages_paid();
+            self.progress.investor_distributed_today = 0;
+
+            // Transfer all fees to creator (will happen in finalization step below)
+        } else {
+            // ===== STEP 5: USE THE DAY'S FIXED INVESTOR SHARE =====
+
+            // With a Token-2022 transfer fee, investors split what is left of the
+            // share after fees so that net amounts received stay pro-rata
+            let transfer_fee = current_transfer_fee(&self.quote_mint.to_account_info())?;
+            let investor_fee_quote = net_budget_after_fee(
+                transfer_fee.as_ref(),
+                self.progress.investor_fee_quote,
+            )?;
+
+            // ===== STEP 6: DISTRIBUTE TO INVESTORS PRO-RATA =====
+
+            let mut total_distributed_this_page = 0u64;
+            let mut transfer_fees_this_page = 0u64;
+            let mut dust_accumulator = self.progress.carry_over_lamports;
+
+            // Grossed-up transfers never spend more than the share left today
+            let mut share_left = self.progress.investor_share_left();
+
+            for i in 0..num_investors {
+                // Get references to stream and payout account upfront
+                let stream_account = &self.remaining_accounts[i];
+                let payout_account = &self.remaining_accounts[num_investors + i];
+
+                // Load this investor's weight (locked amount or static table weight)
+                let weight = InvestorWeight::load(
+                    stream_account,
+                    self.policy,
+                    self.weight_table.map(|table| &**table),
+                )?;
+                let locked_i = weight.weight_at(snapshot_ts)?;
+
+                // Calculate pro-rata payout
+                let payout = calculate_pro_rata_share(
+                    investor_fee_quote,
+                    locked_i,
+                    locked_total,
+                )?;
+
+                // Dust carried from earlier days is owed on top of today's payout
+                let carried = self.registry_page.carried[i];
+                let owed = payout
+                    .checked_add(carried)
+                    .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+                match distribution_mode {
+                    DistributionMode::Push => {
+                        let payable = if pay_native_sol {
+                            self.verify_native_recipient(&weight, payout_account)?;
+
+                            // A wallet that does not exist yet only accepts a rent-exempt deposit
+                            owed >= self.policy.min_payout_lamports
+                                && (payout_account.lamports() > 0
+                                    || owed >= Rent::get()?.minimum_balance(0))
+                        } else {
+                            self.verify_payout_ata(&weight, payout_account)?;
+                            owed >= self.policy.min_payout_lamports
+                        };
+
+                        if payable {
+                            if pay_native_sol {
+                                // Paid in lamports once the page's total is unwrapped
+                                native_payouts.push((payout_account.clone(), owed));
+                                self.settle_carried_dust(i, payout_account, day_id, owed, current_time)?;
+
+                                total_distributed_this_page = total_distributed_this_page
+                                    .checked_add(payout)
+                                    .ok_or(ErrorCode::ArithmeticOverflow)?;
+                                share_left = share_left.saturating_sub(payout);
+                            } else {
+                                // Transfer quote tokens to investor (grossed up for the transfer fee)
+                                let (gross, delivered) = gross_up_within_budget(
+                                    transfer_fee.as_ref(),
+                                    owed,
+                                    carried.saturating_add(share_left),
+                                )?;
+                                self.transfer_from_treasury(payout_account.clone(), gross)?;
+                                self.settle_carried_dust(i, payout_account, day_id, delivered, current_time)?;
+
+                                transfer_fees_this_page = transfer_fees_this_page
+                                    .checked_add(gross - delivered)
+                                    .ok_or(ErrorCode::ArithmeticOverflow)?;
+                                total_distributed_this_page = total_distributed_this_page
+                                    .checked_add(delivered.saturating_sub(carried))
+                                    .ok_or(ErrorCode::ArithmeticOverflow)?;
+                                share_left = share_left.saturating_sub(gross - carried);
+                            }
+                        } else {
+                            // Below dust threshold - carry forward for this investor
+                            share_left = share_left.saturating_sub(payout);
+                            self.registry_page.carried[i] = owed;
+                            self.position_owner.carried_dust = self.position_owner.carried_dust
+                                .checked_add(payout)
+                                .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+                            dust_accumulator = dust_accumulator
+                                .checked_add(payout)
+                                .ok_or(ErrorCode::ArithmeticOverflow)?;
+                        }
+                    }
+                    DistributionMode::Pull => {
+                        // Ledger credits move no tokens, so no dust threshold applies;
+                        // the credit covers the transfer fee charged when claiming
+                        let (gross, delivered) = gross_up_within_budget(
+                            transfer_fee.as_ref(),
+                            owed,
+                            carried.saturating_add(share_left),
+                        )?;
+                        self.credit_ledger(stream_account, payout_account, gross, day_id)?;
+                        self.settle_carried_dust(i, payout_account, day_id, delivered, current_time)?;
+                        share_left = share_left.saturating_sub(gross - carried);
+
+                        // Credited amounts stay in the treasury until claimed
+                        self.position_owner.pending_claims = self.position_owner.pending_claims
+                            .checked_add(gross)
+                            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+                        transfer_fees_this_page = transfer_fees_this_page
+                            .checked_add(gross - delivered)
+                            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+                        total_distributed_this_page = total_distributed_this_page
+                            .checked_add(delivered.saturating_sub(carried))
+                            .ok_or(ErrorCode::ArithmeticOverflow)?;
+                    }
+                    DistributionMode::Merkle => return err!(ErrorCode::WrongDistributionMode),
+                }
+            }
+
+            // ===== STEP 7: UPDATE PROGRESS =====
+
+            let progress = &mut *self.progress;
+            progress.investor_distributed_today = progress.investor_distributed_today
+                .checked_add(total_distributed_this_page)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+            progress.transfer_fees_today = progress.transfer_fees_today
+                .checked_add(transfer_fees_this_page)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+            progress.carry_over_lamports = dust_accumulator;
+            progress.last_crank_ts = current_time;
+
+            emit!(InvestorPayoutPage {
+                day_id,
+                page: page_index,
+                investors_paid: num_investors as u16,
+                total_distributed: total_distributed_this_page,
+                dust_carried: dust_accumulator,
+                transfer_fees: transfer_fees_this_page,
+                timestamp: current_time,
+            });
+        }
+
+        // ===== STEP 8: FINALIZE DAY (if last page) =====
+
+        if self.progress.is_complete() {
+            let creator_remainder = self.progress.close_day(self.policy.roll_over_undistributed)?;
+
+            if creator_remainder > 0 {
+                // Transfer remainder to creator
+                match &self.native_sol {
+                    Some(native) if pay_native_sol => {
+                        native_payouts.push((native.creator_wallet.to_account_info(), creator_remainder));
+                    }
+                    _ => self.transfer_from_treasury(
+                        self.creator_quote_ata.to_account_info(),
+                        creator_remainder,
+                    )?,
+                }
+
+                self.progress.creator_distributed_today = creator_remainder;
+            }
+
+            self.progress.is_finalized = true;
+
+            emit!(CreatorPayoutDayClosed {
+                day_id,
+                creator_amount: creator_remainder,
+                total_investors_paid: self.progress.investor_distributed_today,
+                total_pages: self.progress.total_pages,
+                rolled_over: self.progress.rollover_lamports,
+                timestamp: current_time,
+            });
+        }
+
+        if !native_payouts.is_empty() {
+            self.pay_native_sol(&native_payouts)?;
+        }
+
+        Ok(())
+    }
+
+    /// Whether payouts go out as lamports (native SOL option with a wSOL quote mint)
+    fn pays_native_sol(&self) -> bool {
+        self.policy.pay_native_sol && self.quote_mint.key() == native_mint::ID
+    }
+
+    /// Native payouts go to the vesting recipient's (or static investor's) wallet
+    fn verify_native_recipient(&self, weight: &InvestorWeight, payout_account: &AccountInfo) -> Result<()> {
+        require!(
+            weight.recipient() == Some(payout_account.key()),
+            ErrorCode::PayoutAccountMismatch
+        );
+        require_keys_eq!(
+            *payout_account.owner,
+            system_program::ID,
+            ErrorCode::PayoutAccountMismatch
+        );
+        Ok(())
+    }
+
+    /// Unwrap the total of a page's native payouts, then pay each wallet in lamports
+    fn pay_native_sol(&self, payouts: &[(AccountInfo<'info>, u64)]) -> Result<()> {
+        let total = payouts
+            .iter()
+            .try_fold(0u64, |total, (_, amount)| total.checked_add(*amount))
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        self.unwrap_to_position_owner(total)?;
+
+        for (wallet, amount) in payouts {
+            self.move_lamports(wallet, *amount)?;
+        }
+
+        Ok(())
+    }
+
+    /// Move wSOL from the treasury into the position owner PDA's lamports by
+    /// closing a temporary token account; the cranker fronts and gets back its rent
+    fn unwrap_to_position_owner(&self, amount: u64) -> Result<()> {
+        let native = self.native_sol.as_ref().ok_or(ErrorCode::NativeSolAccountsRequired)?;
+        let unwrap_account = native.unwrap_account.to_account_info();
+        let vault = self.position_owner.vault;
+        let unwrap_seeds: &[&[u8]] = &[NATIVE_UNWRAP_SEED, vault.as_ref(), &[native.unwrap_bump]];
+        let owner_seeds: &[&[u8]] = &[
+            INVESTOR_FEE_POS_OWNER_SEED,
+            vault.as_ref(),
+            &[self.position_owner.bump],
+        ];
+
+        let space = spl_token::state::Account::LEN;
+        let rent = Rent::get()?.minimum_balance(space);
+        let existing_lamports = unwrap_account.lamports();
+        let rent_paid = rent.saturating_sub(existing_lamports);
+        let system = self.system_program.to_account_info();
+
+        // Lamports sent to the address up front must not block the account's creation
+        if existing_lamports == 0 {
+            system_program::create_account(
+                CpiContext::new_with_signer(
+                    system.clone(),
+                    CreateAccount {
+                        from: self.cranker.to_account_info(),
+                        to: unwrap_account.clone(),
+                    },
+                    &[unwrap_seeds],
+                ),
+                rent,
+                space as u64,
+                &self.token_program.key(),
+            )?;
+        } else {
+            if rent_paid > 0 {
+                system_program::transfer(
+                    CpiContext::new(
+                        system.clone(),
+                        Transfer {
+                            from: self.cranker.to_account_info(),
+                            to: unwrap_account.clone(),
+                        },
+                    ),
+                    rent_paid,
+                )?;
+            }
+            system_program::allocate(
+                CpiContext::new_with_signer(
+                    system.clone(),
+                    Allocate { account_to_allocate: unwrap_account.clone() },
+                    &[unwrap_seeds],
+                ),
+                space as u64,
+            )?;
+            system_program::assign(
+                CpiContext::new_with_signer(
+                    system,
+                    Assign { account_to_assign: unwrap_account.clone() },
+                    &[unwrap_seeds],
+                ),
+                &self.token_program.key(),
+            )?;
+        }
+
+        token_interface::initialize_account3(CpiContext::new(
+            self.token_program.to_account_info(),
+            InitializeAccount3 {
+                account: unwrap_account.clone(),
+                mint: self.quote_mint.to_account_info(),
+                authority: self.position_owner.to_account_info(),
+            },
+        ))?;
+
+        self.transfer_from_treasury(unwrap_account.clone(), amount)?;
+
+        // Closing a wSOL account releases its balance and rent as lamports
+        token_interface::close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: unwrap_account,
+                destination: self.position_owner.to_account_info(),
+                authority: self.position_owner.to_account_info(),
+            },
+            &[owner_seeds],
+        ))?;
+
+        self.move_lamports(&self.cranker.to_account_info(), rent_paid)
+    }
+
+    /// Pay lamports held by the position owner PDA (program-owned, so debited directly)
+    fn move_lamports(&self, to: &AccountInfo<'info>, amount: u64) -> Result<()> {
+        let from = self.position_owner.to_account_info();
+        let from_lamports = from.lamports()
+            .checked_sub(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let to_lamports = to.lamports()
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        **from.try_borrow_mut_lamports()? = from_lamports;
+        **to.try_borrow_mut_lamports()? = to_lamports;
+        Ok(())
+    }
+
+    /// Clear an investor's carried dust once it has been paid or credited
+    fn settle_carried_dust(
+        &mut self,
+        i: usize,
+        payout_account: &AccountInfo,
+        day_id: u64,
+        total_paid: u64,
+        current_time: i64,
+    ) -> Result<()> {
+        let carried = self.registry_page.carried[i];
+        if carried == 0 {
+            return Ok(());
+        }
+
+        self.registry_page.carried[i] = 0;
+        self.position_owner.remove_carried_dust(carried)?;
+
+        emit!(CarriedDustPaid {
+            vault: self.position_owner.vault,
+            day_id,
+            stream: self.registry_page.entries[i].stream,
+            payout_account: payout_account.key(),
+            carried_amount: carried,
+            total_paid,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// Payout ATA must belong to the investor and hold the quote mint
+    fn verify_payout_ata(&self, weight: &InvestorWeight, payout_account: &AccountInfo) -> Result<()> {
+        require_keys_eq!(
+            *payout_account.owner,
+            self.token_program.key(),
+            ErrorCode::PayoutAccountMismatch
+        );
+        let investor_ata = TokenAccount::try_deserialize(
+            &mut &payout_account.try_borrow_data()?[..],
+        )?;
+        require!(
+            weight.is_payout_account(payout_account.key, &investor_ata),
+            ErrorCode::PayoutAccountMismatch
+        );
+        require_keys_eq!(
+            investor_ata.mint,
+            self.position_owner.quote_mint,
+            ErrorCode::PayoutAccountMismatch
+        );
+        Ok(())
+    }
+
+    /// Credit a payout to the investor's ledger (pull distribution mode)
+    fn credit_ledger(
+        &self,
+        stream_account: &AccountInfo,
+        ledger_account: &AccountInfo,
+        payout: u64,
+        day_id: u64,
+    ) -> Result<()> {
+        // Ledgers are only created by open_investor_ledger, bound to one vesting account
+        require_keys_eq!(*ledger_account.owner, crate::ID, ErrorCode::InvalidInvestorLedger);
+        let mut ledger = InvestorLedger::try_deserialize(
+            &mut &ledger_account.try_borrow_data()?[..],
+        )?;
+        require_keys_eq!(ledger.vault, self.position_owner.vault, ErrorCode::InvalidInvestorLedger);
+        require_keys_eq!(
+            ledger.vesting_account,
+            stream_account.key(),
+            ErrorCode::InvalidInvestorLedger
+        );
+
+        ledger.accrued = ledger.accrued
+            .checked_add(payout)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        ledger.last_day = day_id;
+
+        ledger.try_serialize(&mut &mut ledger_account.try_borrow_mut_data()?[..])
+    }
+
+    /// Transfer quote tokens out of the treasury, signed by the position owner PDA
+    fn transfer_from_treasury(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        let seeds = &[
+            INVESTOR_FEE_POS_OWNER_SEED,
+            self.position_owner.vault.as_ref(),
+            &[self.position_owner.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.treasury_quote_ata.to_account_info(),
+                    mint: self.quote_mint.to_account_info(),
+                    to,
+                    authority: self.position_owner.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+            self.quote_mint.decimals,
+        )
+    }
+}
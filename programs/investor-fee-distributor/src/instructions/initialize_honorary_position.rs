@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token, TokenAccount};
-use crate::{constants::*, events::*, state::*, dynamic_amm};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::{constants::*, error::ErrorCode, events::*, state::*, dynamic_amm};
 
 #[derive(Accounts)]
 #[instruction(vault: Pubkey)]
@@ -20,40 +20,48 @@ pub struct InitializeHonoraryPosition<'info> {
     )]
     pub policy_config: Account<'info, PolicyConfig>,
 
-    /// CHECK: DAMM v2 pool account - validated by cp-amm program
+    /// DAMM v1 pool account (deserialized to check its token mints)
     #[account(mut)]
-    pub pool: UncheckedAccount<'info>,
+    pub pool: Box<Account<'info, dynamic_amm::accounts::Pool>>,
 
     /// LP mint of the pool
-    pub lp_mint: Account<'info, Mint>,
+    pub lp_mint: InterfaceAccount<'info, Mint>,
 
     /// CHECK: Lock escrow to be created by cp-amm
     #[account(mut)]
     pub lock_escrow: UncheckedAccount<'info>,
 
     /// Quote mint (Token B in pool)
-    pub quote_mint: Account<'info, Mint>,
+    #[account(
+        constraint = quote_mint.key() == pool.token_b_mint @ ErrorCode::InvalidTokenMint,
+    )]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
 
     /// Base mint (Token A in pool)
-    pub base_mint: Account<'info, Mint>,
+    #[account(
+        constraint = base_mint.key() == pool.token_a_mint @ ErrorCode::InvalidTokenMint,
+    )]
+    pub base_mint: InterfaceAccount<'info, Mint>,
 
     /// Treasury ATA for quote token (owned by investor_fee_position_owner PDA)
     #[account(
         init_if_needed,
         payer = payer,
         associated_token::mint = quote_mint,
-        associated_token::authority = investor_fee_position_owner
+        associated_token::authority = investor_fee_position_owner,
+        associated_token::token_program = token_program,
     )]
-    pub treasury_quote_ata: Account<'info, TokenAccount>,
+    pub treasury_quote_ata: InterfaceAccount<'info, TokenAccount>,
 
     /// Treasury ATA for base token (should remain empty - for validation)
     #[account(
         init_if_needed,
         payer = payer,
         associated_token::mint = base_mint,
-        associated_token::authority = investor_fee_position_owner
+        associated_token::authority = investor_fee_position_owner,
+        associated_token::token_program = token_program,
     )]
-    pub treasury_base_ata: Account<'info, TokenAccount>,
+    pub treasury_base_ata: InterfaceAccount<'info, TokenAccount>,
 
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -64,37 +72,26 @@ pub struct InitializeHonoraryPosition<'info> {
     #[account(address = dynamic_amm::ID)]
     pub dynamic_amm_program: UncheckedAccount<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(
+pub(crate) fn handler(
     ctx: Context<InitializeHonoraryPosition>,
     vault: Pubkey,
 ) -> Result<()> {
     let clock = Clock::get()?;
 
-    // Step 1: Validate pool configuration for quote-only fees
-    // SECURITY NOTE: Pool config validation for collectFeeMode
-    //
-    // DAMM v2 pools support a `collect_fee_mode` parameter:
-    // - Mode 0: Collect both token A and token B fees
-    // - Mode 1: Collect only token B (quote token) fees
-    //
-    // For quote-only fee distribution, we MUST verify collect_fee_mode == 1
-    //
-    // Implementation options:
-    // 1. Parse pool account and check embedded config (if available in account data)
-    // 2. Pass pool config account separately and deserialize
-    // 3. Rely on runtime validation in crank_distribution (base_balance == 0)
-    //
-    // Current approach: Runtime validation in crank_distribution enforces quote-only
-    // by checking treasury_base_ata.amount == 0 before each distribution.
-    // This prevents distribution if any base fees are present.
-    //
-    // TODO: For enhanced security, add pool config account to InitializeHonoraryPosition
-    // and validate collect_fee_mode == 1 at initialization time.
+    // Step 1: Validate base fee handling
+    // A DAMM v1 pool stores no collect fee mode: its swap fees always accrue to
+    // LPs in both token A and token B, so the lock escrow collects base fees too.
+    // That is only accepted when the policy swaps or quarantines base fees;
+    // otherwise a cp-amm OnlyB pool is required (see initialize_honorary_position_cp_amm)
+    require!(
+        ctx.accounts.policy_config.handles_base_fees(),
+        ErrorCode::PoolNotQuoteOnlyFees
+    );
 
     msg!("Creating honorary lock escrow for quote-only fee collection");
 
@@ -130,13 +127,16 @@ pub fn handler(
     owner.bump = ctx.bumps.investor_fee_position_owner;
     owner.vault = vault;
     owner.pool = ctx.accounts.pool.key();
-    owner.lock_escrow = ctx.accounts.lock_escrow.key();
+    owner.position = ctx.accounts.lock_escrow.key();
     owner.lp_mint = ctx.accounts.lp_mint.key();
     owner.quote_mint = ctx.accounts.quote_mint.key();
     owner.base_mint = ctx.accounts.base_mint.key();
     owner.created_at = clock.unix_timestamp;
     owner.last_fee_claim = clock.unix_timestamp;
     owner.total_fees_claimed = 0;
+    owner.position_kind = PositionKind::DynamicAmmLockEscrow;
+    owner.position_nft_mint = Pubkey::default();
+    owner.collect_fee_mode = CollectFeeMode::BothToken;
 
     emit!(HonoraryPositionInitialized {
         vault,
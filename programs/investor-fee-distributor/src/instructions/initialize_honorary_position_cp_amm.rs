@@ -0,0 +1,176 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use anchor_spl::token_2022::Token2022;
+use crate::{constants::*, error::ErrorCode, events::*, state::*, utils::*, cp_amm};
+
+#[derive(Accounts)]
+#[instruction(vault: Pubkey)]
+pub struct InitializeHonoraryPositionCpAmm<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + InvestorFeePositionOwner::INIT_SPACE,
+        seeds = [INVESTOR_FEE_POS_OWNER_SEED, vault.as_ref()],
+        bump
+    )]
+    pub investor_fee_position_owner: Account<'info, InvestorFeePositionOwner>,
+
+    #[account(
+        seeds = [POLICY_CONFIG_SEED, vault.as_ref()],
+        bump = policy_config.bump,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    /// CHECK: DAMM v2 pool account - owner, discriminator and fee mode checked in handler
+    #[account(mut)]
+    pub pool: UncheckedAccount<'info>,
+
+    /// Position NFT mint (fresh keypair, created by cp-amm)
+    #[account(mut)]
+    pub position_nft_mint: Signer<'info>,
+
+    /// CHECK: Position NFT token account - created by cp-amm for the position owner PDA
+    #[account(mut)]
+    pub position_nft_account: UncheckedAccount<'info>,
+
+    /// CHECK: Position account - created by cp-amm
+    #[account(mut)]
+    pub position: UncheckedAccount<'info>,
+
+    /// CHECK: cp-amm pool authority - validated by cp-amm
+    pub pool_authority: UncheckedAccount<'info>,
+
+    /// CHECK: cp-amm event authority - validated by cp-amm
+    pub event_authority: UncheckedAccount<'info>,
+
+    /// Quote mint (Token B in pool; SPL Token or Token-2022)
+    #[account(mint::token_program = token_program)]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
+
+    /// Base mint (Token A in pool; SPL Token or Token-2022)
+    #[account(mint::token_program = base_token_program)]
+    pub base_mint: InterfaceAccount<'info, Mint>,
+
+    /// Treasury ATA for quote token (owned by investor_fee_position_owner PDA)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = quote_mint,
+        associated_token::authority = investor_fee_position_owner,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_quote_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Treasury ATA for base token (should remain empty - for validation)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = base_mint,
+        associated_token::authority = investor_fee_position_owner,
+        associated_token::token_program = base_token_program,
+    )]
+    pub treasury_base_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub authority: Signer<'info>,
+
+    /// CHECK: cp-amm (DAMM v2) program
+    #[account(address = cp_amm::ID)]
+    pub cp_amm_program: UncheckedAccount<'info>,
+
+    /// Token-2022 program (cp-amm position NFTs are Token-2022 mints)
+    pub token_2022_program: Program<'info, Token2022>,
+
+    /// Quote token program
+    pub token_program: Interface<'info, TokenInterface>,
+    /// Base token program (may differ from the quote token program)
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<InitializeHonoraryPositionCpAmm>,
+    vault: Pubkey,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Step 1: Validate pool configuration for quote-only fees and mint orientation
+    // cp-amm pools with collect_fee_mode == OnlyB accrue fees in token B (quote) only
+    let pool_state = parse_cp_amm_pool(&ctx.accounts.pool.to_account_info())?;
+    let collect_fee_mode = pool_state.fee_mode()?;
+    require!(
+        collect_fee_mode == CollectFeeMode::OnlyB,
+        ErrorCode::PoolNotQuoteOnlyFees
+    );
+
+    // OnlyB collects token B, so the quote mint must be the pool's token B
+    require_keys_eq!(
+        ctx.accounts.base_mint.key(),
+        pool_state.token_a_mint,
+        ErrorCode::InvalidTokenMint
+    );
+    require_keys_eq!(
+        ctx.accounts.quote_mint.key(),
+        pool_state.token_b_mint,
+        ErrorCode::InvalidTokenMint
+    );
+
+    msg!("Creating honorary cp-amm position for quote-only fee collection");
+
+    // Step 2: Create position NFT owned by the PDA via CPI to cp-amm
+    // The PDA only receives the NFT here, so no PDA signature is required
+    let cpi_accounts = cp_amm::cpi::accounts::CreatePosition {
+        owner: ctx.accounts.investor_fee_position_owner.to_account_info(),
+        position_nft_mint: ctx.accounts.position_nft_mint.to_account_info(),
+        position_nft_account: ctx.accounts.position_nft_account.to_account_info(),
+        pool: ctx.accounts.pool.to_account_info(),
+        position: ctx.accounts.position.to_account_info(),
+        pool_authority: ctx.accounts.pool_authority.to_account_info(),
+        payer: ctx.accounts.payer.to_account_info(),
+        token_program: ctx.accounts.token_2022_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        event_authority: ctx.accounts.event_authority.to_account_info(),
+        program: ctx.accounts.cp_amm_program.to_account_info(),
+    };
+
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.cp_amm_program.to_account_info(),
+        cpi_accounts,
+    );
+
+    cp_amm::cpi::create_position(cpi_ctx)?;
+
+    msg!("Position created successfully");
+
+    // Step 3: Initialize state
+    let owner = &mut ctx.accounts.investor_fee_position_owner;
+    owner.bump = ctx.bumps.investor_fee_position_owner;
+    owner.vault = vault;
+    owner.pool = ctx.accounts.pool.key();
+    owner.position = ctx.accounts.position.key();
+    owner.lp_mint = Pubkey::default();
+    owner.quote_mint = ctx.accounts.quote_mint.key();
+    owner.base_mint = ctx.accounts.base_mint.key();
+    owner.created_at = clock.unix_timestamp;
+    owner.last_fee_claim = clock.unix_timestamp;
+    owner.total_fees_claimed = 0;
+    owner.position_kind = PositionKind::CpAmmPosition;
+    owner.position_nft_mint = ctx.accounts.position_nft_mint.key();
+    owner.collect_fee_mode = collect_fee_mode;
+
+    emit!(HonoraryPositionInitialized {
+        vault,
+        pool: ctx.accounts.pool.key(),
+        position: ctx.accounts.position.key(),
+        quote_mint: ctx.accounts.quote_mint.key(),
+        base_mint: ctx.accounts.base_mint.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Honorary position initialized - ready to accrue quote-only fees");
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::{constants::*, error::ErrorCode, events::*, state::*};
+use crate::{constants::*, events::*, state::*};
 
 #[derive(Accounts)]
 #[instruction(vault: Pubkey)]
@@ -21,7 +21,7 @@ pub struct InitializePolicy<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(
+pub(crate) fn handler(
     ctx: Context<InitializePolicy>,
     vault: Pubkey,
     investor_fee_share_bps: u16,
@@ -31,11 +31,7 @@ pub fn handler(
     creator_quote_ata: Pubkey,
 ) -> Result<()> {
     // Validate inputs
-    require!(
-        investor_fee_share_bps <= MAX_BPS,
-        ErrorCode::InvalidFeeShareBps
-    );
-    require!(y0_total_streamed > 0, ErrorCode::InvalidY0Amount);
+    PolicyConfig::validate_params(investor_fee_share_bps, y0_total_streamed)?;
 
     let policy = &mut ctx.accounts.policy_config;
     let clock = Clock::get()?;
@@ -48,6 +44,10 @@ pub fn handler(
     policy.min_payout_lamports = min_payout_lamports;
     policy.y0_total_streamed = y0_total_streamed;
     policy.creator_quote_ata = creator_quote_ata;
+    policy.pending_authority = Pubkey::default();
+    policy.vesting_mint = Pubkey::default();
+    policy.allowed_senders = Vec::new();
+    policy.vesting_programs = Vec::new();
 
     emit!(PolicyConfigCreated {
         vault,
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::Discriminator;
+use crate::{constants::*, error::ErrorCode, events::*, state::*};
+
+#[derive(Accounts)]
+#[instruction(vault: Pubkey)]
+pub struct MigrateDailyProgress<'info> {
+    /// CHECK: Daily progress PDA in the legacy layout - decoded and rewritten by the handler
+    #[account(
+        mut,
+        seeds = [DAILY_PROGRESS_SEED, vault.as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub daily_progress: UncheckedAccount<'info>,
+
+    /// Policy configuration (already migrated, proves the authority)
+    #[account(
+        seeds = [POLICY_CONFIG_SEED, vault.as_ref()],
+        bump = policy_config.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    /// Pays the rent for the larger account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow a daily progress account created by the first program release to the current layout
+pub(crate) fn handler(ctx: Context<MigrateDailyProgress>, vault: Pubkey) -> Result<()> {
+    let progress_info = ctx.accounts.daily_progress.to_account_info();
+    let old_space = progress_info.data_len();
+    let new_space = 8 + DailyProgress::INIT_SPACE;
+
+    // Only accounts still in the legacy layout are rewritten
+    require!(
+        old_space == 8 + LegacyDailyProgress::INIT_SPACE,
+        ErrorCode::DailyProgressNotLegacy
+    );
+    let legacy = {
+        let data = progress_info.try_borrow_data()?;
+        require!(
+            data[..8] == DailyProgress::DISCRIMINATOR,
+            ErrorCode::DailyProgressNotLegacy
+        );
+        LegacyDailyProgress::deserialize(&mut &data[8..])?
+    };
+
+    // Top up rent for the new size, then grow the account in place
+    let rent_due = Rent::get()?
+        .minimum_balance(new_space)
+        .saturating_sub(progress_info.lamports());
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: progress_info.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    progress_info.realloc(new_space, true)?;
+
+    let progress = DailyProgress::from_legacy(legacy);
+    progress.try_serialize(&mut &mut progress_info.try_borrow_mut_data()?[..])?;
+
+    emit!(DailyProgressMigrated {
+        vault,
+        day_id: progress.day_id,
+        old_space: old_space as u64,
+        new_space: new_space as u64,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::Discriminator;
+use crate::{constants::*, error::ErrorCode, events::*, state::*};
+
+#[derive(Accounts)]
+#[instruction(vault: Pubkey)]
+pub struct MigratePolicyConfig<'info> {
+    /// CHECK: Policy config PDA in the legacy layout - decoded and rewritten by the handler
+    #[account(
+        mut,
+        seeds = [POLICY_CONFIG_SEED, vault.as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub policy_config: UncheckedAccount<'info>,
+
+    /// Pays the rent for the larger account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow a policy created by the first program release to the current layout
+pub(crate) fn handler(ctx: Context<MigratePolicyConfig>, vault: Pubkey) -> Result<()> {
+    let policy_info = ctx.accounts.policy_config.to_account_info();
+    let old_space = policy_info.data_len();
+    let new_space = 8 + PolicyConfig::INIT_SPACE;
+
+    // Only accounts still in the legacy layout are rewritten
+    require!(
+        old_space == 8 + LegacyPolicyConfig::INIT_SPACE,
+        ErrorCode::PolicyConfigNotLegacy
+    );
+    let legacy = {
+        let data = policy_info.try_borrow_data()?;
+        require!(
+            data[..8] == PolicyConfig::DISCRIMINATOR,
+            ErrorCode::PolicyConfigNotLegacy
+        );
+        LegacyPolicyConfig::deserialize(&mut &data[8..])?
+    };
+
+    require_keys_eq!(
+        legacy.authority,
+        ctx.accounts.authority.key(),
+        ErrorCode::Unauthorized
+    );
+
+    // Top up rent for the new size, then grow the account in place
+    let rent_due = Rent::get()?
+        .minimum_balance(new_space)
+        .saturating_sub(policy_info.lamports());
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: policy_info.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    policy_info.realloc(new_space, true)?;
+
+    let policy = PolicyConfig::from_legacy(legacy);
+    policy.try_serialize(&mut &mut policy_info.try_borrow_mut_data()?[..])?;
+
+    emit!(PolicyConfigMigrated {
+        vault,
+        authority: policy.authority,
+        old_space: old_space as u64,
+        new_space: new_space as u64,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
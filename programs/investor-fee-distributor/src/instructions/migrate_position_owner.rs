@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::Discriminator;
+use crate::{constants::*, error::ErrorCode, events::*, state::*};
+
+#[derive(Accounts)]
+#[instruction(vault: Pubkey)]
+pub struct MigratePositionOwner<'info> {
+    /// CHECK: Position owner PDA in the legacy layout - decoded and rewritten by the handler
+    #[account(
+        mut,
+        seeds = [INVESTOR_FEE_POS_OWNER_SEED, vault.as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub investor_fee_position_owner: UncheckedAccount<'info>,
+
+    /// Policy configuration (already migrated, proves the authority)
+    #[account(
+        seeds = [POLICY_CONFIG_SEED, vault.as_ref()],
+        bump = policy_config.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    /// Pays the rent for the larger account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow a position owner created by the first program release to the current layout
+pub(crate) fn handler(ctx: Context<MigratePositionOwner>, vault: Pubkey) -> Result<()> {
+    let owner_info = ctx.accounts.investor_fee_position_owner.to_account_info();
+    let old_space = owner_info.data_len();
+    let new_space = 8 + InvestorFeePositionOwner::INIT_SPACE;
+
+    // Only accounts still in the legacy layout are rewritten
+    require!(
+        old_space == 8 + LegacyInvestorFeePositionOwner::INIT_SPACE,
+        ErrorCode::PositionOwnerNotLegacy
+    );
+    let legacy = {
+        let data = owner_info.try_borrow_data()?;
+        require!(
+            data[..8] == InvestorFeePositionOwner::DISCRIMINATOR,
+            ErrorCode::PositionOwnerNotLegacy
+        );
+        LegacyInvestorFeePositionOwner::deserialize(&mut &data[8..])?
+    };
+
+    // Top up rent for the new size, then grow the account in place
+    let rent_due = Rent::get()?
+        .minimum_balance(new_space)
+        .saturating_sub(owner_info.lamports());
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: owner_info.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    owner_info.realloc(new_space, true)?;
+
+    let owner = InvestorFeePositionOwner::from_legacy(legacy);
+    owner.try_serialize(&mut &mut owner_info.try_borrow_mut_data()?[..])?;
+
+    emit!(PositionOwnerMigrated {
+        vault,
+        position: owner.position,
+        old_space: old_space as u64,
+        new_space: new_space as u64,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
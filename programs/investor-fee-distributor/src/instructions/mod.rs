@@ -1,9 +1,76 @@
+pub mod accept_authority;
+pub mod add_weight_entry;
+pub mod cancel_authority_transfer;
+pub mod claim_fees;
+pub mod claim_merkle_fees;
+pub mod close_investor_registry_page;
 pub mod crank_distribution;
+pub mod crank_distribution_cp_amm;
 pub mod crank_distribution_full;
+pub(crate) mod distribution;
 pub mod initialize_honorary_position;
+pub mod initialize_honorary_position_cp_amm;
 pub mod initialize_policy;
+pub mod migrate_daily_progress;
+pub mod migrate_policy_config;
+pub mod migrate_position_owner;
+pub mod open_investor_ledger;
+pub mod post_merkle_root;
+pub mod propose_authority;
+pub mod reclaim_expired_distribution;
+pub mod remove_weight_entry;
+pub mod set_base_fee_handling;
+pub mod set_base_fee_swap;
+pub mod set_catch_up_missed_days;
+pub mod set_claim_delegate;
+pub mod set_distribution_mode;
+pub mod set_global_pause;
+pub mod set_guardian;
+pub mod set_investor_registry_page;
+pub mod set_native_sol_payouts;
+pub mod set_paused;
+pub mod set_roll_over_undistributed;
+pub mod set_vesting_filter;
+pub mod set_weight_mode;
+pub mod snapshot_locked_total;
+pub mod sweep_base_fees;
+pub mod update_policy;
+pub mod update_weight_entry;
 
+pub use accept_authority::*;
+pub use add_weight_entry::*;
+pub use cancel_authority_transfer::*;
+pub use claim_fees::*;
+pub use claim_merkle_fees::*;
+pub use close_investor_registry_page::*;
 pub use crank_distribution::*;
+pub use crank_distribution_cp_amm::*;
 pub use crank_distribution_full::*;
 pub use initialize_honorary_position::*;
+pub use initialize_honorary_position_cp_amm::*;
 pub use initialize_policy::*;
+pub use migrate_daily_progress::*;
+pub use migrate_policy_config::*;
+pub use migrate_position_owner::*;
+pub use open_investor_ledger::*;
+pub use post_merkle_root::*;
+pub use propose_authority::*;
+pub use reclaim_expired_distribution::*;
+pub use remove_weight_entry::*;
+pub use set_base_fee_handling::*;
+pub use set_base_fee_swap::*;
+pub use set_catch_up_missed_days::*;
+pub use set_claim_delegate::*;
+pub use set_distribution_mode::*;
+pub use set_global_pause::*;
+pub use set_guardian::*;
+pub use set_investor_registry_page::*;
+pub use set_native_sol_payouts::*;
+pub use set_paused::*;
+pub use set_roll_over_undistributed::*;
+pub use set_vesting_filter::*;
+pub use set_weight_mode::*;
+pub use snapshot_locked_total::*;
+pub use sweep_base_fees::*;
+pub use update_policy::*;
+pub use update_weight_entry::*;
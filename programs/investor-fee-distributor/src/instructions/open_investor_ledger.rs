@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::{constants::*, error::ErrorCode, events::*, state::*, utils::*};
+
+#[derive(Accounts)]
+pub struct OpenInvestorLedger<'info> {
+    /// Payer for the ledger account (permissionless - anyone can call)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [POLICY_CONFIG_SEED, investor_fee_position_owner.vault.as_ref()],
+        bump = policy_config.bump,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    #[account(
+        seeds = [INVESTOR_FEE_POS_OWNER_SEED, investor_fee_position_owner.vault.as_ref()],
+        bump = investor_fee_position_owner.bump,
+    )]
+    pub investor_fee_position_owner: Account<'info, InvestorFeePositionOwner>,
+
+    /// Static investor weights (required in static table weight mode)
+    #[account(
+        seeds = [WEIGHT_TABLE_SEED, investor_fee_position_owner.vault.as_ref()],
+        bump = weight_table.bump,
+    )]
+    pub weight_table: Option<Account<'info, WeightTable>>,
+
+    /// CHECK: Registry slot account (vesting account, or investor wallet in static
+    /// table mode) - decoded and validated in handler
+    pub vesting_account: UncheckedAccount<'info>,
+
+    /// Investor's quote token account, proving who owns the ledger
+    #[account(
+        constraint = investor_quote_ata.mint == investor_fee_position_owner.quote_mint @ ErrorCode::InvalidTokenMint,
+    )]
+    pub investor_quote_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + InvestorLedger::INIT_SPACE,
+        seeds = [
+            INVESTOR_LEDGER_SEED,
+            investor_fee_position_owner.vault.as_ref(),
+            vesting_account.key().as_ref(),
+        ],
+        bump
+    )]
+    pub investor_ledger: Account<'info, InvestorLedger>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the claimable ledger for one registry slot (pull distribution mode)
+pub(crate) fn handler(ctx: Context<OpenInvestorLedger>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // The quote ATA must be a valid payout account for this slot
+    let weight = InvestorWeight::load(
+        &ctx.accounts.vesting_account.to_account_info(),
+        &ctx.accounts.policy_config,
+        ctx.accounts.weight_table.as_deref(),
+    )?;
+    let investor_quote_ata = &ctx.accounts.investor_quote_ata;
+    require!(
+        weight.is_payout_account(&investor_quote_ata.key(), investor_quote_ata),
+        ErrorCode::PayoutAccountMismatch
+    );
+
+    let vault = ctx.accounts.investor_fee_position_owner.vault;
+    let ledger = &mut ctx.accounts.investor_ledger;
+    ledger.bump = ctx.bumps.investor_ledger;
+    ledger.vault = vault;
+    ledger.vesting_account = ctx.accounts.vesting_account.key();
+    ledger.owner = investor_quote_ata.owner;
+    ledger.delegate = Pubkey::default();
+    ledger.accrued = 0;
+    ledger.claimed = 0;
+    ledger.last_day = 0;
+
+    emit!(InvestorLedgerOpened {
+        vault,
+        ledger: ledger.key(),
+        vesting_account: ledger.vesting_account,
+        owner: ledger.owner,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
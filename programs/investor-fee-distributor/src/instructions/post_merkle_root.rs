@@ -0,0 +1,284 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::{constants::*, error::ErrorCode, events::*, state::*};
+
+#[derive(Accounts)]
+#[instruction(day_id: u64)]
+pub struct PostMerkleRoot<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [POLICY_CONFIG_SEED, policy_config.vault.as_ref()],
+        bump = policy_config.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    /// CHECK: Program-wide guardian config PDA (may not exist yet) - read for the global pause
+    #[account(seeds = [GUARDIAN_CONFIG_SEED], bump)]
+    pub guardian_config: UncheckedAccount<'info>,
+
+    /// Honorary position owner PDA (treasury authority)
+    #[account(
+        mut,
+        seeds = [INVESTOR_FEE_POS_OWNER_SEED, policy_config.vault.as_ref()],
+        bump = investor_fee_position_owner.bump,
+    )]
+    pub investor_fee_position_owner: Account<'info, InvestorFeePositionOwner>,
+
+    /// Daily progress (day being settled, rolled-over share and unassigned
+    /// catch-up fees)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + DailyProgress::INIT_SPACE,
+        seeds = [DAILY_PROGRESS_SEED, policy_config.vault.as_ref()],
+        bump
+    )]
+    pub daily_progress: Account<'info, DailyProgress>,
+
+    /// One distribution per day
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + DailyDistribution::INIT_SPACE,
+        seeds = [
+            DAILY_DISTRIBUTION_SEED,
+            policy_config.vault.as_ref(),
+            &day_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub daily_distribution: Account<'info, DailyDistribution>,
+
+    /// Quote mint (SPL Token or Token-2022)
+    #[account(
+        address = investor_fee_position_owner.quote_mint @ ErrorCode::InvalidTokenMint,
+        mint::token_program = token_program,
+    )]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
+
+    /// Treasury quote token account (owned by investor_fee_position_owner)
+    #[account(
+        mut,
+        constraint = treasury_quote_ata.mint == investor_fee_position_owner.quote_mint @ ErrorCode::InvalidTokenMint,
+        constraint = treasury_quote_ata.owner == investor_fee_position_owner.key() @ ErrorCode::InvalidPosition,
+    )]
+    pub treasury_quote_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Treasury base token account (should be empty - for validation)
+    #[account(
+        constraint = treasury_base_ata.mint == investor_fee_position_owner.base_mint @ ErrorCode::InvalidTokenMint,
+        constraint = treasury_base_ata.owner == investor_fee_position_owner.key() @ ErrorCode::InvalidPosition,
+    )]
+    pub treasury_base_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Creator's quote token ATA (for remainder)
+    #[account(
+        mut,
+        constraint = creator_quote_ata.key() == policy_config.creator_quote_ata @ ErrorCode::InvalidPolicy,
+    )]
+    pub creator_quote_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Post the Merkle root of (index, investor, amount, locked) leaves for the day
+/// being settled (`day_id`, earlier than today during a catch-up run)
+/// Fees must already sit in the treasury; the locked total is the one the root
+/// commits to, and the creator remainder is paid immediately
+pub(crate) fn handler(
+    ctx: Context<PostMerkleRoot>,
+    day_id: u64,
+    merkle_root: [u8; 32],
+    locked_total: u64,
+    total_amount: u64,
+    num_leaves: u32,
+    claim_window_secs: i64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let today = (current_time / SECONDS_PER_DAY) as u64;
+    let policy = &ctx.accounts.policy_config;
+    policy.require_not_paused(&ctx.accounts.guardian_config)?;
+
+    require!(
+        policy.distribution_mode == DistributionMode::Merkle,
+        ErrorCode::WrongDistributionMode
+    );
+    require!(
+        claim_window_secs >= MIN_MERKLE_CLAIM_WINDOW,
+        ErrorCode::InvalidClaimWindow
+    );
+    let base_balance = ctx.accounts.treasury_base_ata.amount;
+    require!(
+        base_balance == 0 || policy.quarantine_base_fees,
+        ErrorCode::BaseFeesDetected
+    );
+    if base_balance > 0 {
+        emit!(BaseFeesQuarantined {
+            vault: policy.vault,
+            day_id,
+            base_mint: ctx.accounts.investor_fee_position_owner.base_mint,
+            amount: base_balance,
+            timestamp: current_time,
+        });
+    }
+
+    // Locked amounts are never paged on-chain in Merkle mode: the root commits
+    // to the locked total, claims are bounded by it, and it cannot exceed Y0
+    require!(
+        policy.weight_mode != WeightMode::VestingLocked
+            || locked_total <= policy.y0_total_streamed,
+        ErrorCode::LockedTotalExceedsAllocation
+    );
+
+    // ===== STEP 0: START THE DAY =====
+
+    let progress = &mut ctx.accounts.daily_progress;
+    if progress.day_id == 0 {
+        // First time initialization
+        progress.bump = ctx.bumps.daily_progress;
+        progress.vault = policy.vault;
+        progress.reset_for_new_day(today, current_time);
+    } else if let Some(old_day_id) = progress.advance_day(
+        today,
+        current_time,
+        policy.catch_up_missed_days,
+    )? {
+        emit!(DailyProgressReset {
+            vault: policy.vault,
+            old_day_id,
+            new_day_id: progress.day_id,
+            timestamp: current_time,
+        });
+    }
+
+    // One root settles the whole day
+    require!(progress.day_id == day_id, ErrorCode::OutsideWindow);
+    require!(!progress.is_finalized, ErrorCode::DayAlreadyFinalized);
+    require!(
+        progress.is_within_window(current_time),
+        ErrorCode::OutsideWindow
+    );
+    progress.locked_total_snapshot = locked_total;
+
+    // ===== STEP 1: DAY'S POOL AND INVESTOR SHARE =====
+
+    // Quote belonging to earlier days stays in the treasury; whatever
+    // exceeds it was deposited as fees
+    let position_owner = &mut ctx.accounts.investor_fee_position_owner;
+    let reserved_carry = ctx.accounts.daily_progress
+        .reserved_carry(position_owner.owed_to_investors()?)?;
+    let balance_after = ctx.accounts.treasury_quote_ata.amount;
+    let balance_before = reserved_carry.min(balance_after);
+    let amount_claimed = position_owner.record_fee_claim(
+        balance_before,
+        balance_after,
+        reserved_carry,
+        current_time,
+    )?;
+
+    // Same pool as the paged path: the day's part of a catch-up run plus the
+    // rolled-over share, clipped by the daily cap (the clip rolls over if enabled)
+    let progress = &mut ctx.accounts.daily_progress;
+    let quote_fees = progress.open_day_pool(amount_claimed)?;
+    let investor_share = policy.investor_share(quote_fees, locked_total)?;
+    progress.fix_investor_fee_quote(
+        investor_share,
+        policy.daily_cap_lamports,
+        policy.roll_over_undistributed,
+    )?;
+
+    // The off-chain leaves must add up to exactly the on-chain investor share
+    require!(
+        total_amount == progress.investor_fee_quote,
+        ErrorCode::InvalidMerkleTotal
+    );
+
+    emit!(QuoteFeesClaimed {
+        day_id,
+        amount_claimed,
+        day_fees: quote_fees,
+        balance_before_claim: balance_before,
+        reserved_carry,
+        position: position_owner.position,
+        timestamp: current_time,
+    });
+
+    // ===== STEP 2: PAY CREATOR REMAINDER =====
+
+    // The whole investor share is credited to the leaves at once
+    progress.investor_distributed_today = total_amount;
+    let creator_amount = progress.close_day(policy.roll_over_undistributed)?;
+
+    if creator_amount > 0 {
+        let seeds = &[
+            INVESTOR_FEE_POS_OWNER_SEED,
+            position_owner.vault.as_ref(),
+            &[position_owner.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.treasury_quote_ata.to_account_info(),
+                    mint: ctx.accounts.quote_mint.to_account_info(),
+                    to: ctx.accounts.creator_quote_ata.to_account_info(),
+                    authority: position_owner.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            creator_amount,
+            ctx.accounts.quote_mint.decimals,
+        )?;
+    }
+
+    // ===== STEP 3: RECORD DISTRIBUTION =====
+
+    let position_owner = &mut ctx.accounts.investor_fee_position_owner;
+    position_owner.pending_claims = position_owner.pending_claims
+        .checked_add(total_amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    // The day is settled by this root; no payout pages follow
+    let progress = &mut ctx.accounts.daily_progress;
+    progress.creator_distributed_today = creator_amount;
+    progress.last_crank_ts = current_time;
+    progress.is_finalized = true;
+
+    let distribution = &mut ctx.accounts.daily_distribution;
+    distribution.bump = ctx.bumps.daily_distribution;
+    distribution.vault = position_owner.vault;
+    distribution.day_id = day_id;
+    distribution.merkle_root = merkle_root;
+    distribution.locked_total = locked_total;
+    distribution.total_amount = total_amount;
+    distribution.claimed_amount = 0;
+    distribution.num_leaves = num_leaves;
+    distribution.posted_at = current_time;
+    distribution.expires_at = current_time
+        .checked_add(claim_window_secs)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    distribution.is_reclaimed = false;
+
+    emit!(MerkleRootPosted {
+        vault: distribution.vault,
+        day_id,
+        merkle_root,
+        locked_total,
+        total_amount,
+        creator_amount,
+        num_leaves,
+        expires_at: distribution.expires_at,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
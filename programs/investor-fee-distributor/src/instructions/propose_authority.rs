@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::ErrorCode, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [POLICY_CONFIG_SEED, policy_config.vault.as_ref()],
+        bump = policy_config.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    let policy = &mut ctx.accounts.policy_config;
+    let clock = Clock::get()?;
+
+    require!(
+        new_authority != Pubkey::default() && new_authority != policy.authority,
+        ErrorCode::InvalidNewAuthority
+    );
+
+    // Overwrites any previous proposal
+    policy.pending_authority = new_authority;
+
+    emit!(AuthorityTransferProposed {
+        vault: policy.vault,
+        authority: policy.authority,
+        pending_authority: new_authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::{constants::*, error::ErrorCode, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct ReclaimExpiredDistribution<'info> {
+    /// Anyone can return an expired distribution to the creator
+    pub cranker: Signer<'info>,
+
+    #[account(
+        seeds = [POLICY_CONFIG_SEED, daily_distribution.vault.as_ref()],
+        bump = policy_config.bump,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    /// CHECK: Program-wide guardian config PDA (may not exist yet) - read for the global pause
+    #[account(seeds = [GUARDIAN_CONFIG_SEED], bump)]
+    pub guardian_config: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            DAILY_DISTRIBUTION_SEED,
+            daily_distribution.vault.as_ref(),
+            &daily_distribution.day_id.to_le_bytes(),
+        ],
+        bump = daily_distribution.bump,
+    )]
+    pub daily_distribution: Account<'info, DailyDistribution>,
+
+    /// Honorary position owner PDA (treasury authority)
+    #[account(
+        mut,
+        seeds = [INVESTOR_FEE_POS_OWNER_SEED, daily_distribution.vault.as_ref()],
+        bump = investor_fee_position_owner.bump,
+    )]
+    pub investor_fee_position_owner: Account<'info, InvestorFeePositionOwner>,
+
+    /// Quote mint (SPL Token or Token-2022)
+    #[account(
+        address = investor_fee_position_owner.quote_mint @ ErrorCode::InvalidTokenMint,
+        mint::token_program = token_program,
+    )]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
+
+    /// Treasury quote token account (owned by investor_fee_position_owner)
+    #[account(
+        mut,
+        constraint = treasury_quote_ata.mint == investor_fee_position_owner.quote_mint @ ErrorCode::InvalidTokenMint,
+        constraint = treasury_quote_ata.owner == investor_fee_position_owner.key() @ ErrorCode::InvalidPosition,
+    )]
+    pub treasury_quote_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Creator's quote token ATA (receives the unclaimed amount)
+    #[account(
+        mut,
+        constraint = creator_quote_ata.key() == policy_config.creator_quote_ata @ ErrorCode::InvalidPolicy,
+    )]
+    pub creator_quote_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Return the unclaimed part of an expired Merkle distribution to the creator
+pub(crate) fn handler(ctx: Context<ReclaimExpiredDistribution>) -> Result<()> {
+    let clock = Clock::get()?;
+    ctx.accounts.policy_config.require_not_paused(&ctx.accounts.guardian_config)?;
+    let distribution = &ctx.accounts.daily_distribution;
+
+    require!(!distribution.is_reclaimed, ErrorCode::DayAlreadyFinalized);
+    require!(
+        clock.unix_timestamp >= distribution.expires_at,
+        ErrorCode::ClaimWindowOpen
+    );
+
+    let creator_amount = distribution.unclaimed();
+
+    if creator_amount > 0 {
+        let position_owner = &ctx.accounts.investor_fee_position_owner;
+        let seeds = &[
+            INVESTOR_FEE_POS_OWNER_SEED,
+            position_owner.vault.as_ref(),
+            &[position_owner.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.treasury_quote_ata.to_account_info(),
+                    mint: ctx.accounts.quote_mint.to_account_info(),
+                    to: ctx.accounts.creator_quote_ata.to_account_info(),
+                    authority: position_owner.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            creator_amount,
+            ctx.accounts.quote_mint.decimals,
+        )?;
+    }
+
+    let position_owner = &mut ctx.accounts.investor_fee_position_owner;
+    position_owner.pending_claims = position_owner.pending_claims
+        .checked_sub(creator_amount)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+    let distribution = &mut ctx.accounts.daily_distribution;
+    distribution.is_reclaimed = true;
+
+    emit!(MerkleDistributionReclaimed {
+        vault: distribution.vault,
+        day_id: distribution.day_id,
+        creator_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
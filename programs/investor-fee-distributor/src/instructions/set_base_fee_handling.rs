@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::ErrorCode, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetBaseFeeHandling<'info> {
+    #[account(
+        mut,
+        seeds = [POLICY_CONFIG_SEED, policy_config.vault.as_ref()],
+        bump = policy_config.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    /// CHECK: Honorary position owner PDA (may not exist yet) - read for the position's fee mode
+    #[account(seeds = [INVESTOR_FEE_POS_OWNER_SEED, policy_config.vault.as_ref()], bump)]
+    pub investor_fee_position_owner: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Set where swept base fees go and whether cranks run with base fees quarantined
+/// (allowed mid-day so a day stuck on base fees can be unblocked)
+pub(crate) fn handler(
+    ctx: Context<SetBaseFeeHandling>,
+    base_fee_destination: Pubkey,
+    quarantine_base_fees: bool,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let policy = &mut ctx.accounts.policy_config;
+
+    policy.base_fee_destination = base_fee_destination;
+    policy.quarantine_base_fees = quarantine_base_fees;
+
+    // A position that accrues base fees needs them swapped or quarantined
+    require!(
+        policy.handles_base_fees()
+            || !InvestorFeePositionOwner::collects_base_fees(&ctx.accounts.investor_fee_position_owner)?,
+        ErrorCode::BaseFeeHandlingRequired
+    );
+
+    emit!(BaseFeeHandlingSet {
+        vault: policy.vault,
+        base_fee_destination,
+        quarantine_base_fees,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::ErrorCode, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetBaseFeeSwap<'info> {
+    #[account(
+        mut,
+        seeds = [POLICY_CONFIG_SEED, policy_config.vault.as_ref()],
+        bump = policy_config.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    /// CHECK: Daily progress PDA (may not exist before the first crank) - read for the in-progress guard
+    #[account(seeds = [DAILY_PROGRESS_SEED, policy_config.vault.as_ref()], bump)]
+    pub daily_progress: UncheckedAccount<'info>,
+
+    /// CHECK: Honorary position owner PDA (may not exist yet) - read for the position's fee mode
+    #[account(seeds = [INVESTOR_FEE_POS_OWNER_SEED, policy_config.vault.as_ref()], bump)]
+    pub investor_fee_position_owner: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Choose whether the DAMM v1 crank swaps base fees into quote, and its bounds
+pub(crate) fn handler(
+    ctx: Context<SetBaseFeeSwap>,
+    swap_base_fees: bool,
+    max_swap_slippage_bps: u16,
+    min_base_swap_amount: u64,
+    min_swap_price: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let day_id = (clock.unix_timestamp / SECONDS_PER_DAY) as u64;
+
+    require!(max_swap_slippage_bps <= MAX_BPS, ErrorCode::InvalidSlippageBps);
+    require!(
+        !swap_base_fees || min_swap_price > 0,
+        ErrorCode::SwapPriceFloorRequired
+    );
+
+    // Refuse changes while today's snapshot or payouts are underway
+    DailyProgress::require_no_day_in_progress(
+        &ctx.accounts.daily_progress,
+        day_id,
+        ErrorCode::DistributionInProgress,
+    )?;
+
+    let policy = &mut ctx.accounts.policy_config;
+
+    policy.swap_base_fees = swap_base_fees;
+    policy.max_swap_slippage_bps = max_swap_slippage_bps;
+    policy.min_base_swap_amount = min_base_swap_amount;
+    policy.min_swap_price = min_swap_price;
+
+    // A position that accrues base fees needs them swapped or quarantined
+    require!(
+        policy.handles_base_fees()
+            || !InvestorFeePositionOwner::collects_base_fees(&ctx.accounts.investor_fee_position_owner)?,
+        ErrorCode::BaseFeeHandlingRequired
+    );
+
+    emit!(BaseFeeSwapSet {
+        vault: policy.vault,
+        swap_base_fees,
+        max_swap_slippage_bps,
+        min_base_swap_amount,
+        min_swap_price,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
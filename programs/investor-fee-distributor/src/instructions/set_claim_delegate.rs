@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::ErrorCode, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetClaimDelegate<'info> {
+    #[account(
+        mut,
+        seeds = [
+            INVESTOR_LEDGER_SEED,
+            investor_ledger.vault.as_ref(),
+            investor_ledger.vesting_account.as_ref(),
+        ],
+        bump = investor_ledger.bump,
+        has_one = owner @ ErrorCode::Unauthorized,
+    )]
+    pub investor_ledger: Account<'info, InvestorLedger>,
+
+    pub owner: Signer<'info>,
+}
+
+/// Allow another wallet to claim on the owner's behalf (default to revoke)
+pub(crate) fn handler(ctx: Context<SetClaimDelegate>, delegate: Pubkey) -> Result<()> {
+    let clock = Clock::get()?;
+    let ledger = &mut ctx.accounts.investor_ledger;
+    ledger.delegate = delegate;
+
+    emit!(ClaimDelegateSet {
+        ledger: ledger.key(),
+        owner: ledger.owner,
+        delegate,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
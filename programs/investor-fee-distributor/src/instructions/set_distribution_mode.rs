@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::ErrorCode, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetDistributionMode<'info> {
+    #[account(
+        mut,
+        seeds = [POLICY_CONFIG_SEED, policy_config.vault.as_ref()],
+        bump = policy_config.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    /// CHECK: Daily progress PDA (may not exist before the first crank) - read for the in-progress guard
+    #[account(seeds = [DAILY_PROGRESS_SEED, policy_config.vault.as_ref()], bump)]
+    pub daily_progress: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Switch between push transfers and pull-based ledger credits
+pub(crate) fn handler(ctx: Context<SetDistributionMode>, distribution_mode: DistributionMode) -> Result<()> {
+    let clock = Clock::get()?;
+    let day_id = (clock.unix_timestamp / SECONDS_PER_DAY) as u64;
+
+    // Refuse changes while today's snapshot or payouts are underway
+    DailyProgress::require_no_day_in_progress(
+        &ctx.accounts.daily_progress,
+        day_id,
+        ErrorCode::DistributionInProgress,
+    )?;
+
+    let policy = &mut ctx.accounts.policy_config;
+
+    emit!(DistributionModeSet {
+        vault: policy.vault,
+        old_distribution_mode: policy.distribution_mode,
+        new_distribution_mode: distribution_mode,
+        timestamp: clock.unix_timestamp,
+    });
+
+    policy.distribution_mode = distribution_mode;
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::ErrorCode, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetGlobalPause<'info> {
+    #[account(
+        mut,
+        seeds = [GUARDIAN_CONFIG_SEED],
+        bump = guardian_config.bump,
+        has_one = guardian @ ErrorCode::Unauthorized,
+    )]
+    pub guardian_config: Account<'info, GuardianConfig>,
+
+    pub guardian: Signer<'info>,
+}
+
+/// Stop or resume distributions for every vault at once
+pub(crate) fn handler(ctx: Context<SetGlobalPause>, paused: bool) -> Result<()> {
+    let clock = Clock::get()?;
+    let config = &mut ctx.accounts.guardian_config;
+
+    config.paused = paused;
+
+    emit!(GlobalPauseSet {
+        guardian: config.guardian,
+        paused,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
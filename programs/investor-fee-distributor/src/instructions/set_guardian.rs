@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::ErrorCode, events::*, state::*, program::InvestorFeeDistributor};
+
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    #[account(
+        init_if_needed,
+        payer = upgrade_authority,
+        space = 8 + GuardianConfig::INIT_SPACE,
+        seeds = [GUARDIAN_CONFIG_SEED],
+        bump
+    )]
+    pub guardian_config: Account<'info, GuardianConfig>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ ErrorCode::Unauthorized)]
+    pub program: Program<'info, InvestorFeeDistributor>,
+
+    #[account(constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key()) @ ErrorCode::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// Program upgrade authority (appoints the guardian)
+    #[account(mut)]
+    pub upgrade_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Appoint (or replace) the guardian that can pause all vaults
+pub(crate) fn handler(ctx: Context<SetGuardian>, guardian: Pubkey) -> Result<()> {
+    let clock = Clock::get()?;
+    let config = &mut ctx.accounts.guardian_config;
+
+    config.bump = ctx.bumps.guardian_config;
+    config.guardian = guardian;
+
+    emit!(GuardianSet {
+        guardian,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
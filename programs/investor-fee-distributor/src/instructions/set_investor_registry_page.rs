@@ -0,0 +1,197 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::{constants::*, error::ErrorCode, events::*, state::*};
+
+#[derive(Accounts)]
+#[instruction(page_index: u16)]
+pub struct SetInvestorRegistryPage<'info> {
+    #[account(
+        mut,
+        seeds = [POLICY_CONFIG_SEED, policy_config.vault.as_ref()],
+        bump = policy_config.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + InvestorRegistryPage::INIT_SPACE,
+        seeds = [INVESTOR_REGISTRY_SEED, policy_config.vault.as_ref(), &page_index.to_le_bytes()],
+        bump
+    )]
+    pub investor_registry_page: Account<'info, InvestorRegistryPage>,
+
+    /// CHECK: Daily progress PDA (may not exist before the first crank) - read for the in-progress guard
+    #[account(seeds = [DAILY_PROGRESS_SEED, policy_config.vault.as_ref()], bump)]
+    pub daily_progress: UncheckedAccount<'info>,
+
+    /// Honorary position owner (required only when removed investors carry dust)
+    #[account(
+        mut,
+        seeds = [INVESTOR_FEE_POS_OWNER_SEED, policy_config.vault.as_ref()],
+        bump = investor_fee_position_owner.bump,
+    )]
+    pub investor_fee_position_owner: Option<Account<'info, InvestorFeePositionOwner>>,
+
+    /// Quote mint (required only when removed investors carry dust)
+    pub quote_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Treasury quote token account (required only when removed investors carry dust)
+    #[account(mut)]
+    pub treasury_quote_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create or overwrite one page of the investor registry
+/// Pages must stay contiguous: only existing pages or the next page may be set
+/// Streams must ascend within the page and follow the previous page's streams
+/// Removed investors that carry dust are paid it (their registered quote ATAs
+/// are passed as remaining accounts, in the page's previous order)
+pub(crate) fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetInvestorRegistryPage<'info>>,
+    page_index: u16,
+    entries: Vec<InvestorEntry>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let day_id = (clock.unix_timestamp / SECONDS_PER_DAY) as u64;
+
+    // Validate inputs
+    require!(
+        !entries.is_empty() && entries.len() <= MAX_INVESTORS_PER_PAGE,
+        ErrorCode::InvalidRegistryPage
+    );
+    InvestorRegistryPage::validate_entries(&entries)?;
+
+    let policy = &mut ctx.accounts.policy_config;
+    require!(
+        page_index <= policy.registry_pages && (page_index as usize) < MAX_PAGES_PER_DAY,
+        ErrorCode::InvalidRegistryPage
+    );
+
+    // Refuse changes while today's pages are being processed
+    DailyProgress::require_no_day_in_progress(
+        &ctx.accounts.daily_progress,
+        day_id,
+        ErrorCode::RegistryLocked,
+    )?;
+
+    let page = &mut ctx.accounts.investor_registry_page;
+    page.bump = ctx.bumps.investor_registry_page;
+    page.vault = policy.vault;
+    page.page_index = page_index;
+    let removed = page.replace_entries(entries);
+
+    if page_index == policy.registry_pages {
+        policy.registry_pages = policy.registry_pages
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+    }
+
+    // Removed investors keep the dust they accrued
+    let dust_released = pay_removed_dust(
+        &removed,
+        ctx.remaining_accounts,
+        ctx.accounts.investor_fee_position_owner.as_mut(),
+        ctx.accounts.quote_mint.as_ref(),
+        ctx.accounts.treasury_quote_ata.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        clock.unix_timestamp,
+    )?;
+
+    let policy = &ctx.accounts.policy_config;
+    let page = &ctx.accounts.investor_registry_page;
+    emit!(InvestorRegistryPageSet {
+        vault: policy.vault,
+        page_index,
+        num_entries: page.entries.len() as u16,
+        registry_pages: policy.registry_pages,
+        dust_released,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Pay investors removed from the registry the dust carried for them, to the
+/// quote ATAs recorded in the registry (`payout_accounts`, in the order of
+/// `removed`); returns the total paid
+pub(crate) fn pay_removed_dust<'info>(
+    removed: &[(InvestorEntry, u64)],
+    payout_accounts: &[AccountInfo<'info>],
+    position_owner: Option<&mut Account<'info, InvestorFeePositionOwner>>,
+    quote_mint: Option<&InterfaceAccount<'info, Mint>>,
+    treasury_quote_ata: Option<&InterfaceAccount<'info, TokenAccount>>,
+    token_program: Option<&Interface<'info, TokenInterface>>,
+    current_time: i64,
+) -> Result<u64> {
+    if removed.is_empty() {
+        return Ok(0);
+    }
+
+    let position_owner = position_owner.ok_or(ErrorCode::PositionOwnerRequired)?;
+    let (quote_mint, treasury_quote_ata, token_program) = match (quote_mint, treasury_quote_ata, token_program) {
+        (Some(mint), Some(treasury), Some(program)) => (mint, treasury, program),
+        _ => return err!(ErrorCode::DustPayoutAccountsRequired),
+    };
+    require_keys_eq!(quote_mint.key(), position_owner.quote_mint, ErrorCode::InvalidTokenMint);
+    require_keys_eq!(treasury_quote_ata.mint, position_owner.quote_mint, ErrorCode::InvalidTokenMint);
+    require_keys_eq!(treasury_quote_ata.owner, position_owner.key(), ErrorCode::InvalidPosition);
+    require!(
+        payout_accounts.len() == removed.len(),
+        ErrorCode::RegistryPageMismatch
+    );
+
+    let vault = position_owner.vault;
+    let seeds = &[
+        INVESTOR_FEE_POS_OWNER_SEED,
+        vault.as_ref(),
+        &[position_owner.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let mut total_paid = 0u64;
+    for ((entry, carried), payout_account) in removed.iter().zip(payout_accounts.iter()) {
+        require_keys_eq!(payout_account.key(), entry.quote_ata, ErrorCode::PayoutAccountMismatch);
+
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                TransferChecked {
+                    from: treasury_quote_ata.to_account_info(),
+                    mint: quote_mint.to_account_info(),
+                    to: payout_account.clone(),
+                    authority: position_owner.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            *carried,
+            quote_mint.decimals,
+        )?;
+        position_owner.remove_carried_dust(*carried)?;
+
+        emit!(CarriedDustPaid {
+            vault,
+            day_id: (current_time / SECONDS_PER_DAY) as u64,
+            stream: entry.stream,
+            payout_account: payout_account.key(),
+            carried_amount: *carried,
+            total_paid: *carried,
+            timestamp: current_time,
+        });
+
+        total_paid = total_paid
+            .checked_add(*carried)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+    }
+
+    Ok(total_paid)
+}
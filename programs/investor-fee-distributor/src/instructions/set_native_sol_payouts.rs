@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::ErrorCode, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetNativeSolPayouts<'info> {
+    #[account(
+        mut,
+        seeds = [POLICY_CONFIG_SEED, policy_config.vault.as_ref()],
+        bump = policy_config.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    /// CHECK: Daily progress PDA (may not exist before the first crank) - read for the in-progress guard
+    #[account(seeds = [DAILY_PROGRESS_SEED, policy_config.vault.as_ref()], bump)]
+    pub daily_progress: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Choose whether push payouts of a wSOL quote mint are unwrapped and paid as lamports
+pub(crate) fn handler(ctx: Context<SetNativeSolPayouts>, pay_native_sol: bool) -> Result<()> {
+    let clock = Clock::get()?;
+    let day_id = (clock.unix_timestamp / SECONDS_PER_DAY) as u64;
+
+    // Refuse changes while today's snapshot or payouts are underway
+    DailyProgress::require_no_day_in_progress(
+        &ctx.accounts.daily_progress,
+        day_id,
+        ErrorCode::DistributionInProgress,
+    )?;
+
+    let policy = &mut ctx.accounts.policy_config;
+
+    policy.pay_native_sol = pay_native_sol;
+
+    emit!(NativeSolPayoutsSet {
+        vault: policy.vault,
+        pay_native_sol,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::ErrorCode, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(
+        mut,
+        seeds = [POLICY_CONFIG_SEED, policy_config.vault.as_ref()],
+        bump = policy_config.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Stop or resume this vault's distributions (allowed mid-day)
+pub(crate) fn handler(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    let clock = Clock::get()?;
+    let policy = &mut ctx.accounts.policy_config;
+
+    policy.paused = paused;
+
+    emit!(DistributionPauseSet {
+        vault: policy.vault,
+        paused,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::ErrorCode, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetRollOverUndistributed<'info> {
+    #[account(
+        mut,
+        seeds = [POLICY_CONFIG_SEED, policy_config.vault.as_ref()],
+        bump = policy_config.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    /// CHECK: Daily progress PDA (may not exist before the first crank) - read for the in-progress guard
+    #[account(seeds = [DAILY_PROGRESS_SEED, policy_config.vault.as_ref()], bump)]
+    pub daily_progress: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Choose whether capped and undistributed investor share rolls into the next day
+pub(crate) fn handler(ctx: Context<SetRollOverUndistributed>, roll_over_undistributed: bool) -> Result<()> {
+    let clock = Clock::get()?;
+    let day_id = (clock.unix_timestamp / SECONDS_PER_DAY) as u64;
+
+    // Refuse changes while today's snapshot or payouts are underway
+    DailyProgress::require_no_day_in_progress(
+        &ctx.accounts.daily_progress,
+        day_id,
+        ErrorCode::DistributionInProgress,
+    )?;
+
+    let policy = &mut ctx.accounts.policy_config;
+
+    policy.roll_over_undistributed = roll_over_undistributed;
+
+    emit!(RollOverUndistributedSet {
+        vault: policy.vault,
+        roll_over_undistributed,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::ErrorCode, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetVestingFilter<'info> {
+    #[account(
+        mut,
+        seeds = [POLICY_CONFIG_SEED, policy_config.vault.as_ref()],
+        bump = policy_config.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    /// CHECK: Daily progress PDA (may not exist before the first crank) - read for the in-progress guard
+    #[account(seeds = [DAILY_PROGRESS_SEED, policy_config.vault.as_ref()], bump)]
+    pub daily_progress: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Set the vesting mint, allowed senders and accepted vesting programs
+pub(crate) fn handler(
+    ctx: Context<SetVestingFilter>,
+    vesting_mint: Pubkey,
+    allowed_senders: Vec<Pubkey>,
+    vesting_programs: Vec<VestingProgram>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let day_id = (clock.unix_timestamp / SECONDS_PER_DAY) as u64;
+
+    // Validate inputs
+    PolicyConfig::validate_vesting_filter(&vesting_mint, &allowed_senders, &vesting_programs)?;
+
+    // Refuse changes while today's snapshot or payouts are underway
+    DailyProgress::require_no_day_in_progress(
+        &ctx.accounts.daily_progress,
+        day_id,
+        ErrorCode::DistributionInProgress,
+    )?;
+
+    let policy = &mut ctx.accounts.policy_config;
+    policy.vesting_mint = vesting_mint;
+    policy.allowed_senders = allowed_senders;
+    policy.vesting_programs = vesting_programs;
+
+    emit!(VestingFilterSet {
+        vault: policy.vault,
+        vesting_mint,
+        allowed_senders: policy.allowed_senders.clone(),
+        vesting_programs: policy.vesting_programs.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
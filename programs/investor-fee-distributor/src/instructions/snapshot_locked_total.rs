@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::ErrorCode, events::*, state::*, utils::*};
+
+#[derive(Accounts)]
+pub struct SnapshotLockedTotal<'info> {
+    /// Cranker (permissionless - anyone can call)
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    /// Daily progress tracking account
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        space = 8 + DailyProgress::INIT_SPACE,
+        seeds = [DAILY_PROGRESS_SEED, investor_fee_position_owner.vault.as_ref()],
+        bump
+    )]
+    pub daily_progress: Account<'info, DailyProgress>,
+
+    /// Policy configuration (registry page count and vesting filter)
+    #[account(
+        seeds = [POLICY_CONFIG_SEED, investor_fee_position_owner.vault.as_ref()],
+        bump = policy_config.bump,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    /// CHECK: Program-wide guardian config PDA (may not exist yet) - read for the global pause
+    #[account(seeds = [GUARDIAN_CONFIG_SEED], bump)]
+    pub guardian_config: UncheckedAccount<'info>,
+
+    /// Honorary position owner PDA
+    #[account(
+        seeds = [INVESTOR_FEE_POS_OWNER_SEED, investor_fee_position_owner.vault.as_ref()],
+        bump = investor_fee_position_owner.bump,
+    )]
+    pub investor_fee_position_owner: Account<'info, InvestorFeePositionOwner>,
+
+    /// Registry page being snapshotted (any order, once per day)
+    #[account(
+        seeds = [
+            INVESTOR_REGISTRY_SEED,
+            investor_fee_position_owner.vault.as_ref(),
+            &investor_registry_page.page_index.to_le_bytes(),
+        ],
+        bump = investor_registry_page.bump,
+    )]
+    pub investor_registry_page: Account<'info, InvestorRegistryPage>,
+
+    /// Registry page right before this one (required unless this is page 0)
+    #[account(
+        seeds = [
+            INVESTOR_REGISTRY_SEED,
+            investor_fee_position_owner.vault.as_ref(),
+            &investor_registry_page.page_index.wrapping_sub(1).to_le_bytes(),
+        ],
+        bump = previous_registry_page.bump,
+    )]
+    pub previous_registry_page: Option<Account<'info, InvestorRegistryPage>>,
+
+    /// Static investor weights (required in static table weight mode)
+    #[account(
+        seeds = [WEIGHT_TABLE_SEED, investor_fee_position_owner.vault.as_ref()],
+        bump = weight_table.bump,
+    )]
+    pub weight_table: Option<Account<'info, WeightTable>>,
+
+    pub system_program: Program<'info, System>,
+
+    // Remaining accounts:
+    // Vesting accounts (or investor wallets in static table mode), read-only,
+    // for this page in registry order
+    // Pattern: [stream_0, stream_1, ..., stream_n]
+}
+
+/// First step of every day: accumulate the locked total across all pages so
+/// that payout pages share a single pro-rata denominator
+pub(crate) fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, SnapshotLockedTotal<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let day_id = (current_time / SECONDS_PER_DAY) as u64;
+
+    ctx.accounts.policy_config.require_not_paused(&ctx.accounts.guardian_config)?;
+
+    let progress = &mut ctx.accounts.daily_progress;
+    let position_owner = &ctx.accounts.investor_fee_position_owner;
+    let registry_page = &ctx.accounts.investor_registry_page;
+
+    // Pages are fixed by the registry, not by the cranker
+    let total_pages = ctx.accounts.policy_config.registry_pages;
+    require!(total_pages > 0, ErrorCode::InvalidTotalPages);
+
+    // ===== STEP 1: 24H GATE & DAY INITIALIZATION =====
+
+    if progress.day_id == 0 {
+        // First time initialization
+        progress.bump = ctx.bumps.daily_progress;
+        progress.vault = position_owner.vault;
+        progress.reset_for_new_day(day_id, current_time);
+        progress.total_pages = total_pages;
+    } else if let Some(old_day_id) = progress.advance_day(
+        day_id,
+        current_time,
+        ctx.accounts.policy_config.catch_up_missed_days,
+    )? {
+        emit!(DailyProgressReset {
+            vault: position_owner.vault,
+            old_day_id,
+            new_day_id: progress.day_id,
+            timestamp: current_time,
+        });
+
+        progress.total_pages = total_pages;
+    }
+
+    // The day being processed (earlier than today during a catch-up run)
+    let day_id = progress.day_id;
+
+    require!(!progress.is_finalized, ErrorCode::DayAlreadyFinalized);
+    require!(
+        progress.is_within_window(current_time),
+        ErrorCode::OutsideWindow
+    );
+    require!(
+        !progress.is_snapshot_complete(),
+        ErrorCode::SnapshotAlreadyComplete
+    );
+
+    // ===== STEP 2: VERIFY PAGE AGAINST REGISTRY =====
+
+    // Pages may be snapshotted in any order, but each exactly once
+    registry_page.verify_streams(ctx.remaining_accounts)?;
+
+    // Streams ascend across pages, so once every page is snapshotted no
+    // stream can have been counted (and later paid) twice
+    registry_page.require_follows(ctx.accounts.previous_registry_page.as_deref())?;
+    progress.mark_snapshot_page(registry_page.page_index)?;
+
+    // ===== STEP 3: ACCUMULATE LOCKED TOTAL =====
+
+    let page_locked = calculate_total_weight(
+        ctx.remaining_accounts,
+        progress.snapshot_ts,
+        &ctx.accounts.policy_config,
+        ctx.accounts.weight_table.as_deref(),
+    )?;
+
+    progress.locked_total_snapshot = progress.locked_total_snapshot
+        .checked_add(page_locked)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    progress.last_crank_ts = current_time;
+
+    emit!(LockedTotalSnapshotPage {
+        day_id,
+        page: registry_page.page_index,
+        page_locked,
+        locked_total: progress.locked_total_snapshot,
+        is_complete: progress.is_snapshot_complete(),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::{constants::*, error::ErrorCode, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct SweepBaseFees<'info> {
+    #[account(
+        seeds = [POLICY_CONFIG_SEED, policy_config.vault.as_ref()],
+        bump = policy_config.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    /// CHECK: Program-wide guardian config PDA (may not exist yet) - read for the global pause
+    #[account(seeds = [GUARDIAN_CONFIG_SEED], bump)]
+    pub guardian_config: UncheckedAccount<'info>,
+
+    /// Honorary position owner PDA (treasury authority)
+    #[account(
+        seeds = [INVESTOR_FEE_POS_OWNER_SEED, policy_config.vault.as_ref()],
+        bump = investor_fee_position_owner.bump,
+    )]
+    pub investor_fee_position_owner: Account<'info, InvestorFeePositionOwner>,
+
+    /// Base mint (SPL Token or Token-2022)
+    #[account(
+        address = investor_fee_position_owner.base_mint @ ErrorCode::InvalidTokenMint,
+        mint::token_program = token_program,
+    )]
+    pub base_mint: InterfaceAccount<'info, Mint>,
+
+    /// Treasury base token account (owned by investor_fee_position_owner)
+    #[account(
+        mut,
+        constraint = treasury_base_ata.mint == investor_fee_position_owner.base_mint @ ErrorCode::InvalidTokenMint,
+        constraint = treasury_base_ata.owner == investor_fee_position_owner.key() @ ErrorCode::InvalidPosition,
+    )]
+    pub treasury_base_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Base token account configured on the policy to receive swept fees
+    #[account(
+        mut,
+        address = policy_config.base_fee_destination @ ErrorCode::InvalidBaseFeeDestination,
+        constraint = destination.mint == investor_fee_position_owner.base_mint @ ErrorCode::InvalidTokenMint,
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Move every base token in the treasury to the policy's base fee destination
+pub(crate) fn handler(ctx: Context<SweepBaseFees>) -> Result<()> {
+    let clock = Clock::get()?;
+    ctx.accounts.policy_config.require_not_paused(&ctx.accounts.guardian_config)?;
+
+    let amount = ctx.accounts.treasury_base_ata.amount;
+    require!(amount > 0, ErrorCode::NoBaseFeesToSweep);
+
+    let position_owner = &ctx.accounts.investor_fee_position_owner;
+    let seeds = &[
+        INVESTOR_FEE_POS_OWNER_SEED,
+        position_owner.vault.as_ref(),
+        &[position_owner.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.treasury_base_ata.to_account_info(),
+                mint: ctx.accounts.base_mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: position_owner.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        ctx.accounts.base_mint.decimals,
+    )?;
+
+    emit!(BaseFeesSwept {
+        vault: position_owner.vault,
+        destination: ctx.accounts.destination.key(),
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::ErrorCode, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct UpdatePolicy<'info> {
+    #[account(
+        mut,
+        seeds = [POLICY_CONFIG_SEED, policy_config.vault.as_ref()],
+        bump = policy_config.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    /// CHECK: Daily progress PDA (may not exist before the first crank) - read for the in-progress guard
+    #[account(seeds = [DAILY_PROGRESS_SEED, policy_config.vault.as_ref()], bump)]
+    pub daily_progress: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<UpdatePolicy>,
+    investor_fee_share_bps: u16,
+    daily_cap_lamports: Option<u64>,
+    min_payout_lamports: u64,
+    y0_total_streamed: u64,
+    creator_quote_ata: Pubkey,
+) -> Result<()> {
+    // Validate inputs
+    PolicyConfig::validate_params(investor_fee_share_bps, y0_total_streamed)?;
+
+    let clock = Clock::get()?;
+    let day_id = (clock.unix_timestamp / SECONDS_PER_DAY) as u64;
+
+    // Refuse changes while today's snapshot or payouts are underway
+    DailyProgress::require_no_day_in_progress(
+        &ctx.accounts.daily_progress,
+        day_id,
+        ErrorCode::DistributionInProgress,
+    )?;
+
+    let policy = &mut ctx.accounts.policy_config;
+
+    emit!(PolicyConfigUpdated {
+        vault: policy.vault,
+        authority: policy.authority,
+        old_investor_fee_share_bps: policy.investor_fee_share_bps,
+        new_investor_fee_share_bps: investor_fee_share_bps,
+        old_daily_cap_lamports: policy.daily_cap_lamports,
+        new_daily_cap_lamports: daily_cap_lamports,
+        old_min_payout_lamports: policy.min_payout_lamports,
+        new_min_payout_lamports: min_payout_lamports,
+        old_y0_total_streamed: policy.y0_total_streamed,
+        new_y0_total_streamed: y0_total_streamed,
+        old_creator_quote_ata: policy.creator_quote_ata,
+        new_creator_quote_ata: creator_quote_ata,
+        timestamp: clock.unix_timestamp,
+    });
+
+    policy.investor_fee_share_bps = investor_fee_share_bps;
+    policy.daily_cap_lamports = daily_cap_lamports;
+    policy.min_payout_lamports = min_payout_lamports;
+    policy.y0_total_streamed = y0_total_streamed;
+    policy.creator_quote_ata = creator_quote_ata;
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::ErrorCode, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct UpdateWeightEntry<'info> {
+    #[account(
+        seeds = [POLICY_CONFIG_SEED, policy_config.vault.as_ref()],
+        bump = policy_config.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub policy_config: Account<'info, PolicyConfig>,
+
+    /// Static investor weights
+    #[account(
+        mut,
+        seeds = [WEIGHT_TABLE_SEED, policy_config.vault.as_ref()],
+        bump = weight_table.bump,
+    )]
+    pub weight_table: Account<'info, WeightTable>,
+
+    /// CHECK: Daily progress PDA (may not exist before the first crank) - read for the in-progress guard
+    #[account(seeds = [DAILY_PROGRESS_SEED, policy_config.vault.as_ref()], bump)]
+    pub daily_progress: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Change an investor's weight in the static weight table
+pub(crate) fn handler(ctx: Context<UpdateWeightEntry>, investor: Pubkey, weight: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let day_id = (clock.unix_timestamp / SECONDS_PER_DAY) as u64;
+
+    // Refuse changes while today's pages are being processed
+    DailyProgress::require_no_day_in_progress(
+        &ctx.accounts.daily_progress,
+        day_id,
+        ErrorCode::RegistryLocked,
+    )?;
+
+    let table = &mut ctx.accounts.weight_table;
+    let old_weight = table.update(&investor, weight)?;
+
+    emit!(WeightEntryChanged {
+        vault: table.vault,
+        investor,
+        old_weight,
+        new_weight: weight,
+        total_weight: table.total_weight,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -16,6 +16,7 @@ pub use utils::*;
 // Declare external programs for CPI
 declare_program!(dynamic_amm);
 declare_program!(dynamic_vault);
+declare_program!(cp_amm);
 
 declare_id!("2UsVYuZY3pEWhWZceW7rH9gyDwLmzYXMuw3exduuAYmn");
 
@@ -44,7 +45,253 @@ pub mod investor_fee_distributor {
         )
     }
 
-    /// Initialize honorary DAMM v2 position for quote-only fee collection
+    /// Update fee distribution policy parameters (authority only)
+    pub fn update_policy(
+        ctx: Context<UpdatePolicy>,
+        investor_fee_share_bps: u16,
+        daily_cap_lamports: Option<u64>,
+        min_payout_lamports: u64,
+        y0_total_streamed: u64,
+        creator_quote_ata: Pubkey,
+    ) -> Result<()> {
+        instructions::update_policy::handler(
+            ctx,
+            investor_fee_share_bps,
+            daily_cap_lamports,
+            min_payout_lamports,
+            y0_total_streamed,
+            creator_quote_ata,
+        )
+    }
+
+    /// Grow a policy from the original account layout to the current one (authority only)
+    pub fn migrate_policy_config(ctx: Context<MigratePolicyConfig>, vault: Pubkey) -> Result<()> {
+        instructions::migrate_policy_config::handler(ctx, vault)
+    }
+
+    /// Grow a daily progress account from the original layout to the current one (authority only)
+    pub fn migrate_daily_progress(ctx: Context<MigrateDailyProgress>, vault: Pubkey) -> Result<()> {
+        instructions::migrate_daily_progress::handler(ctx, vault)
+    }
+
+    /// Grow the honorary position owner from the original layout to the current one (authority only)
+    pub fn migrate_position_owner(ctx: Context<MigratePositionOwner>, vault: Pubkey) -> Result<()> {
+        instructions::migrate_position_owner::handler(ctx, vault)
+    }
+
+    /// Propose a new policy authority (step 1 of 2, current authority only)
+    pub fn propose_authority(
+        ctx: Context<ProposeAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_authority::handler(ctx, new_authority)
+    }
+
+    /// Accept a pending policy authority transfer (step 2 of 2, new authority signs)
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority::handler(ctx)
+    }
+
+    /// Cancel a pending policy authority transfer (current authority only)
+    pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+        instructions::cancel_authority_transfer::handler(ctx)
+    }
+
+    /// Create or overwrite a page of the investor registry (authority only)
+    pub fn set_investor_registry_page<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetInvestorRegistryPage<'info>>,
+        page_index: u16,
+        entries: Vec<InvestorEntry>,
+    ) -> Result<()> {
+        instructions::set_investor_registry_page::handler(ctx, page_index, entries)
+    }
+
+    /// Close the last page of the investor registry (authority only)
+    pub fn close_investor_registry_page<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseInvestorRegistryPage<'info>>,
+    ) -> Result<()> {
+        instructions::close_investor_registry_page::handler(ctx)
+    }
+
+    /// Set the vesting mint, allowed senders and vesting programs (authority only)
+    pub fn set_vesting_filter(
+        ctx: Context<SetVestingFilter>,
+        vesting_mint: Pubkey,
+        allowed_senders: Vec<Pubkey>,
+        vesting_programs: Vec<VestingProgram>,
+    ) -> Result<()> {
+        instructions::set_vesting_filter::handler(ctx, vesting_mint, allowed_senders, vesting_programs)
+    }
+
+    /// Choose vesting-locked or static table investor weights (authority only)
+    pub fn set_weight_mode(ctx: Context<SetWeightMode>, weight_mode: WeightMode) -> Result<()> {
+        instructions::set_weight_mode::handler(ctx, weight_mode)
+    }
+
+    /// Add an investor to the static weight table (authority only)
+    pub fn add_weight_entry(
+        ctx: Context<AddWeightEntry>,
+        investor: Pubkey,
+        weight: u64,
+    ) -> Result<()> {
+        instructions::add_weight_entry::handler(ctx, investor, weight)
+    }
+
+    /// Update an investor's static weight (authority only)
+    pub fn update_weight_entry(
+        ctx: Context<UpdateWeightEntry>,
+        investor: Pubkey,
+        weight: u64,
+    ) -> Result<()> {
+        instructions::update_weight_entry::handler(ctx, investor, weight)
+    }
+
+    /// Remove an investor from the static weight table (authority only)
+    pub fn remove_weight_entry(ctx: Context<RemoveWeightEntry>, investor: Pubkey) -> Result<()> {
+        instructions::remove_weight_entry::handler(ctx, investor)
+    }
+
+    /// Choose push transfers or pull-based ledger credits (authority only)
+    pub fn set_distribution_mode(
+        ctx: Context<SetDistributionMode>,
+        distribution_mode: DistributionMode,
+    ) -> Result<()> {
+        instructions::set_distribution_mode::handler(ctx, distribution_mode)
+    }
+
+    /// Roll capped and undistributed investor share into the next day (authority only)
+    pub fn set_roll_over_undistributed(
+        ctx: Context<SetRollOverUndistributed>,
+        roll_over_undistributed: bool,
+    ) -> Result<()> {
+        instructions::set_roll_over_undistributed::handler(ctx, roll_over_undistributed)
+    }
+
+    /// Catch up missed days one by one instead of merging them (authority only)
+    pub fn set_catch_up_missed_days(
+        ctx: Context<SetCatchUpMissedDays>,
+        catch_up_missed_days: bool,
+    ) -> Result<()> {
+        instructions::set_catch_up_missed_days::handler(ctx, catch_up_missed_days)
+    }
+
+    /// Pay wSOL payouts as native SOL lamports (authority only)
+    pub fn set_native_sol_payouts(
+        ctx: Context<SetNativeSolPayouts>,
+        pay_native_sol: bool,
+    ) -> Result<()> {
+        instructions::set_native_sol_payouts::handler(ctx, pay_native_sol)
+    }
+
+    /// Set the base fee sweep destination and quarantine mode (authority only)
+    pub fn set_base_fee_handling(
+        ctx: Context<SetBaseFeeHandling>,
+        base_fee_destination: Pubkey,
+        quarantine_base_fees: bool,
+    ) -> Result<()> {
+        instructions::set_base_fee_handling::handler(ctx, base_fee_destination, quarantine_base_fees)
+    }
+
+    /// Swap base fees into quote before DAMM v1 distributions (authority only)
+    pub fn set_base_fee_swap(
+        ctx: Context<SetBaseFeeSwap>,
+        swap_base_fees: bool,
+        max_swap_slippage_bps: u16,
+        min_base_swap_amount: u64,
+        min_swap_price: u64,
+    ) -> Result<()> {
+        instructions::set_base_fee_swap::handler(
+            ctx,
+            swap_base_fees,
+            max_swap_slippage_bps,
+            min_base_swap_amount,
+            min_swap_price,
+        )
+    }
+
+    /// Pause this vault's distributions (authority only)
+    pub fn pause(ctx: Context<SetPaused>) -> Result<()> {
+        instructions::set_paused::handler(ctx, true)
+    }
+
+    /// Resume this vault's distributions (authority only)
+    pub fn unpause(ctx: Context<SetPaused>) -> Result<()> {
+        instructions::set_paused::handler(ctx, false)
+    }
+
+    /// Appoint the program-wide guardian (upgrade authority only)
+    pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Pubkey) -> Result<()> {
+        instructions::set_guardian::handler(ctx, guardian)
+    }
+
+    /// Pause distributions for all vaults (guardian only)
+    pub fn pause_all(ctx: Context<SetGlobalPause>) -> Result<()> {
+        instructions::set_global_pause::handler(ctx, true)
+    }
+
+    /// Resume distributions for all vaults (guardian only)
+    pub fn unpause_all(ctx: Context<SetGlobalPause>) -> Result<()> {
+        instructions::set_global_pause::handler(ctx, false)
+    }
+
+    /// Create the claimable ledger for a registry slot (permissionless)
+    pub fn open_investor_ledger(ctx: Context<OpenInvestorLedger>) -> Result<()> {
+        instructions::open_investor_ledger::handler(ctx)
+    }
+
+    /// Set or revoke the wallet allowed to claim for a ledger (ledger owner only)
+    pub fn set_claim_delegate(ctx: Context<SetClaimDelegate>, delegate: Pubkey) -> Result<()> {
+        instructions::set_claim_delegate::handler(ctx, delegate)
+    }
+
+    /// Withdraw quote credited to an investor ledger (owner or delegate)
+    pub fn claim_fees(ctx: Context<ClaimFees>) -> Result<()> {
+        instructions::claim_fees::handler(ctx)
+    }
+
+    /// Post the Merkle root settling the current day's investor payouts (authority only, Merkle mode)
+    pub fn post_merkle_root(
+        ctx: Context<PostMerkleRoot>,
+        day_id: u64,
+        merkle_root: [u8; 32],
+        locked_total: u64,
+        total_amount: u64,
+        num_leaves: u32,
+        claim_window_secs: i64,
+    ) -> Result<()> {
+        instructions::post_merkle_root::handler(
+            ctx,
+            day_id,
+            merkle_root,
+            locked_total,
+            total_amount,
+            num_leaves,
+            claim_window_secs,
+        )
+    }
+
+    /// Claim one leaf of a Merkle distribution with its proof
+    pub fn claim_merkle_fees(
+        ctx: Context<ClaimMerkleFees>,
+        index: u32,
+        amount: u64,
+        locked: u64,
+        proof: Vec<MerkleProofNode>,
+    ) -> Result<()> {
+        instructions::claim_merkle_fees::handler(ctx, index, amount, locked, proof)
+    }
+
+    /// Return the unclaimed part of an expired Merkle distribution to the creator
+    pub fn reclaim_expired_distribution(ctx: Context<ReclaimExpiredDistribution>) -> Result<()> {
+        instructions::reclaim_expired_distribution::handler(ctx)
+    }
+
+    /// Send base fees in the treasury to the configured destination (authority only)
+    pub fn sweep_base_fees(ctx: Context<SweepBaseFees>) -> Result<()> {
+        instructions::sweep_base_fees::handler(ctx)
+    }
+
+    /// Initialize honorary DAMM v1 lock escrow for quote-only fee collection
     pub fn initialize_honorary_position(
         ctx: Context<InitializeHonoraryPosition>,
         vault: Pubkey,
@@ -52,23 +299,46 @@ pub mod investor_fee_distributor {
         instructions::initialize_honorary_position::handler(ctx, vault)
     }
 
+    /// Initialize honorary DAMM v2 (cp-amm) position NFT for quote-only fee collection
+    pub fn initialize_honorary_position_cp_amm(
+        ctx: Context<InitializeHonoraryPositionCpAmm>,
+        vault: Pubkey,
+    ) -> Result<()> {
+        instructions::initialize_honorary_position_cp_amm::handler(ctx, vault)
+    }
+
+    /// Permissionless locked-total snapshot - first step of each day
+    /// Must cover every registry page before any payout page can be cranked
+    pub fn snapshot_locked_total<'info>(
+        ctx: Context<'_, '_, '_, 'info, SnapshotLockedTotal<'info>>,
+    ) -> Result<()> {
+        instructions::snapshot_locked_total::handler(ctx)
+    }
+
     /// Permissionless 24-hour distribution crank (manual fee transfer version)
     /// Use this when fees are manually transferred to treasury
     pub fn crank_distribution<'info>(
         ctx: Context<'_, '_, '_, 'info, CrankDistribution<'info>>,
-        total_pages: u16,
         investor_data: Vec<InvestorData>,
     ) -> Result<()> {
-        instructions::crank_distribution::handler(ctx, total_pages, investor_data)
+        instructions::crank_distribution::handler(ctx, investor_data)
     }
 
     /// Permissionless 24-hour distribution crank (full CPI version)
     /// Use this when automatically claiming fees from lock escrow via CPI
     pub fn crank_distribution_full<'info>(
         ctx: Context<'_, '_, '_, 'info, CrankDistributionFull<'info>>,
-        total_pages: u16,
         investor_data: Vec<InvestorData>,
     ) -> Result<()> {
-        instructions::crank_distribution_full::handler_full(ctx, total_pages, investor_data)
+        instructions::crank_distribution_full::handler_full(ctx, investor_data)
+    }
+
+    /// Permissionless 24-hour distribution crank (cp-amm CPI version)
+    /// Use this when claiming fees from a DAMM v2 position NFT via CPI
+    pub fn crank_distribution_cp_amm<'info>(
+        ctx: Context<'_, '_, '_, 'info, CrankDistributionCpAmm<'info>>,
+        investor_data: Vec<InvestorData>,
+    ) -> Result<()> {
+        instructions::crank_distribution_cp_amm::handler_cp_amm(ctx, investor_data)
     }
 }
@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
+
+/// One day's Merkle-root distribution of the investor share
+#[account]
+pub struct DailyDistribution {
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+    /// Vault this distribution serves
+    pub vault: Pubkey,
+    /// Day identifier (unix_timestamp / 86400)
+    pub day_id: u64,
+    /// Root of the (index, investor, amount, locked) leaves; every node also
+    /// commits to the locked total beneath it
+    pub merkle_root: [u8; 32],
+    /// Locked total committed at the root, used for the investor share
+    pub locked_total: u64,
+    /// Sum of all leaf amounts (equals the day's investor share)
+    pub total_amount: u64,
+    /// Amount claimed so far
+    pub claimed_amount: u64,
+    /// Number of leaves in the tree
+    pub num_leaves: u32,
+    /// Timestamp when the root was posted
+    pub posted_at: i64,
+    /// Claims close at this timestamp; the rest becomes reclaimable by the creator
+    pub expires_at: i64,
+    /// Whether the unclaimed remainder has been returned to the creator
+    pub is_reclaimed: bool,
+    /// Reserved for future upgrades
+    pub reserved: [u8; 32],
+}
+
+impl DailyDistribution {
+    pub const INIT_SPACE: usize =
+        1 +     // bump
+        32 +    // vault
+        8 +     // day_id
+        32 +    // merkle_root
+        8 +     // locked_total
+        8 +     // total_amount
+        8 +     // claimed_amount
+        4 +     // num_leaves
+        8 +     // posted_at
+        8 +     // expires_at
+        1 +     // is_reclaimed
+        32;     // reserved
+
+    /// Check if claims are still open
+    pub fn is_claimable(&self, current_time: i64) -> bool {
+        !self.is_reclaimed && current_time < self.expires_at
+    }
+
+    /// Amount not yet claimed
+    pub fn unclaimed(&self) -> u64 {
+        self.total_amount.saturating_sub(self.claimed_amount)
+    }
+
+    /// Largest amount a leaf with `locked` may pay: its pro-rata part of the
+    /// day's investor share (rounded down)
+    pub fn max_leaf_amount(&self, locked: u64) -> Result<u64> {
+        if self.locked_total == 0 {
+            return Ok(0);
+        }
+
+        let share = (self.total_amount as u128)
+            .checked_mul(locked as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / self.locked_total as u128;
+
+        u64::try_from(share).map_err(|_| ErrorCode::ArithmeticOverflow.into())
+    }
+}
+
+/// Marker that a leaf has been claimed (its existence blocks double claims)
+#[account]
+pub struct MerkleClaimReceipt {
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+    /// Investor that claimed
+    pub investor: Pubkey,
+    /// Amount claimed
+    pub amount: u64,
+}
+
+impl MerkleClaimReceipt {
+    pub const INIT_SPACE: usize =
+        1 +     // bump
+        32 +    // investor
+        8;      // amount
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_leaf_amount_is_pro_rata() {
+        let distribution = DailyDistribution {
+            bump: 0,
+            vault: Pubkey::default(),
+            day_id: 1,
+            merkle_root: [0u8; 32],
+            locked_total: 3_000,
+            total_amount: 1_000,
+            claimed_amount: 0,
+            num_leaves: 3,
+            posted_at: 0,
+            expires_at: 0,
+            is_reclaimed: false,
+            reserved: [0u8; 32],
+        };
+
+        assert_eq!(distribution.max_leaf_amount(1_000).unwrap(), 333);
+        assert_eq!(distribution.max_leaf_amount(3_000).unwrap(), 1_000);
+        assert_eq!(distribution.max_leaf_amount(0).unwrap(), 0);
+
+        // Nothing is payable against an empty locked total
+        let empty = DailyDistribution { locked_total: 0, ..distribution };
+        assert_eq!(empty.max_leaf_amount(1_000).unwrap(), 0);
+    }
+}
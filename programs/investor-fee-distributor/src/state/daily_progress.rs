@@ -1,4 +1,41 @@
 use anchor_lang::prelude::*;
+use crate::{constants::*, error::ErrorCode};
+
+/// DailyProgress layout written by the first program release, before the
+/// page bitmaps, snapshot and carry fields were added
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct LegacyDailyProgress {
+    pub bump: u8,
+    pub vault: Pubkey,
+    pub day_id: u64,
+    pub window_start: i64,
+    pub last_crank_ts: i64,
+    pub total_quote_claimed_today: u64,
+    pub investor_distributed_today: u64,
+    pub creator_distributed_today: u64,
+    pub carry_over_lamports: u64,
+    pub current_page: u16,
+    pub total_pages: u16,
+    pub is_finalized: bool,
+    pub reserved: [u8; 32],
+}
+
+impl LegacyDailyProgress {
+    pub const INIT_SPACE: usize =
+        1 +     // bump
+        32 +    // vault
+        8 +     // day_id
+        8 +     // window_start
+        8 +     // last_crank_ts
+        8 +     // total_quote_claimed_today
+        8 +     // investor_distributed_today
+        8 +     // creator_distributed_today
+        8 +     // carry_over_lamports
+        2 +     // current_page
+        2 +     // total_pages
+        1 +     // is_finalized
+        32;     // reserved
+}
 
 /// Tracks progress of fee distribution within a 24-hour window
 #[account]
@@ -19,16 +56,38 @@ pub struct DailyProgress {
     pub investor_distributed_today: u64,
     /// Total distributed to creator today
     pub creator_distributed_today: u64,
-    /// Dust carried over from previous pages
+    /// Dust carried into investor balances today (not part of the creator remainder)
     pub carry_over_lamports: u64,
-    /// Current page number (0-indexed)
+    /// Number of payout pages processed today
     pub current_page: u16,
-    /// Total pages for this day
+    /// Total pages for this day (fixed at day start)
     pub total_pages: u16,
     /// Whether this day is finalized
     pub is_finalized: bool,
+    /// Timestamp at which locked amounts are evaluated for the whole day
+    pub snapshot_ts: i64,
+    /// Locked total accumulated across all snapshot pages
+    pub locked_total_snapshot: u64,
+    /// Number of pages included in the locked-total snapshot
+    pub snapshot_pages: u16,
+    /// Bitmap of page indices included in the locked-total snapshot
+    pub snapshot_bitmap: [u8; PAGE_BITMAP_BYTES],
+    /// Bitmap of page indices already paid out
+    pub payout_bitmap: [u8; PAGE_BITMAP_BYTES],
+    /// Investor share of today's fees, fixed on the first payout page
+    pub investor_fee_quote: u64,
+    /// Investor share rolled into the next day's pool (kept across day resets)
+    pub rollover_lamports: u64,
+    /// Rolled-over investor share included in today's pool
+    pub rollover_in_today: u64,
+    /// Last day of the current catch-up run (equals day_id when not catching up)
+    pub catch_up_through_day: u64,
+    /// Fees claimed during a catch-up run not yet assigned to a day (held in the treasury)
+    pub catch_up_pool: u64,
+    /// Token-2022 transfer fees paid on top of today's investor payouts
+    pub transfer_fees_today: u64,
     /// Reserved for future upgrades
-    pub reserved: [u8; 32],
+    pub reserved: [u8; 8],
 }
 
 impl DailyProgress {
@@ -45,11 +104,122 @@ impl DailyProgress {
         2 +     // current_page
         2 +     // total_pages
         1 +     // is_finalized
-        32;     // reserved
+        8 +     // snapshot_ts
+        8 +     // locked_total_snapshot
+        2 +     // snapshot_pages
+        PAGE_BITMAP_BYTES + // snapshot_bitmap
+        PAGE_BITMAP_BYTES + // payout_bitmap
+        8 +     // investor_fee_quote
+        8 +     // rollover_lamports
+        8 +     // rollover_in_today
+        8 +     // catch_up_through_day
+        8 +     // catch_up_pool
+        8 +     // transfer_fees_today
+        8;      // reserved
 
-    /// Check if within the current 24h window
+    /// Carry legacy progress over to the current layout
+    /// The legacy crank paid pages in order, so pages below `current_page` are
+    /// marked paid; no snapshot was taken, so an unfinished day resumes only
+    /// once its locked total is snapshotted
+    pub fn from_legacy(legacy: LegacyDailyProgress) -> Self {
+        let mut payout_bitmap = [0u8; PAGE_BITMAP_BYTES];
+        for page_index in 0..legacy.current_page.min(legacy.total_pages) {
+            set_page(&mut payout_bitmap, page_index);
+        }
+
+        Self {
+            bump: legacy.bump,
+            vault: legacy.vault,
+            day_id: legacy.day_id,
+            window_start: legacy.window_start,
+            last_crank_ts: legacy.last_crank_ts,
+            total_quote_claimed_today: legacy.total_quote_claimed_today,
+            investor_distributed_today: legacy.investor_distributed_today,
+            creator_distributed_today: legacy.creator_distributed_today,
+            carry_over_lamports: legacy.carry_over_lamports,
+            current_page: count_pages(&payout_bitmap),
+            total_pages: legacy.total_pages,
+            is_finalized: legacy.is_finalized,
+            snapshot_ts: legacy.window_start,
+            locked_total_snapshot: 0,
+            snapshot_pages: 0,
+            snapshot_bitmap: [0u8; PAGE_BITMAP_BYTES],
+            payout_bitmap,
+            investor_fee_quote: 0,
+            rollover_lamports: 0,
+            rollover_in_today: 0,
+            catch_up_through_day: legacy.day_id,
+            catch_up_pool: 0,
+            transfer_fees_today: 0,
+            reserved: [0u8; 8],
+        }
+    }
+
+    /// Check if within the current 24h window (extended to the end of a catch-up run)
     pub fn is_within_window(&self, current_time: i64) -> bool {
-        current_time >= self.window_start && current_time < self.window_start + 86400
+        current_time >= self.window_start && current_time < self.window_end()
+    }
+
+    /// End of the window in which the current day may be cranked
+    pub fn window_end(&self) -> i64 {
+        (self.catch_up_through_day.max(self.day_id) as i64 + 1) * SECONDS_PER_DAY
+    }
+
+    /// Check if the current day is the one being processed on `day_id`
+    /// (a missed day counts while its catch-up run lasts)
+    pub fn covers_day(&self, day_id: u64) -> bool {
+        self.day_id <= day_id && day_id <= self.catch_up_through_day.max(self.day_id)
+    }
+
+    /// Day to process next when cranking resumes on `today`
+    pub fn next_day_id(&self, today: u64, catch_up_missed_days: bool) -> u64 {
+        if !catch_up_missed_days || self.day_id == 0 {
+            return today;
+        }
+
+        let oldest = today.saturating_sub(MAX_CATCH_UP_DAYS - 1);
+        self.day_id.saturating_add(1).clamp(oldest, today)
+    }
+
+    /// Extend the current day into a catch-up run ending on `through_day`
+    pub fn begin_catch_up(&mut self, through_day: u64) {
+        self.catch_up_through_day = through_day;
+        if through_day > self.day_id {
+            // Missed days are evaluated at their own window start
+            self.snapshot_ts = self.window_start;
+        }
+    }
+
+    /// Move past a settled (or expired) day on `today`, starting the next day to
+    /// process; returns the previous day_id if the progress was reset
+    pub fn advance_day(
+        &mut self,
+        today: u64,
+        current_time: i64,
+        catch_up_missed_days: bool,
+    ) -> Result<Option<u64>> {
+        if self.covers_day(today) && !(self.is_finalized && self.day_id < today) {
+            return Ok(None);
+        }
+
+        // New day - check 24h gate
+        require!(
+            current_time >= self.window_start + SECONDS_PER_DAY,
+            ErrorCode::TooEarlyForNextDay
+        );
+
+        // In catch-up mode, missed days are processed one by one up to today
+        let old_day_id = self.day_id;
+        let next_day = self.next_day_id(today, catch_up_missed_days);
+        self.reset_for_new_day(next_day, current_time);
+        self.begin_catch_up(today);
+
+        Ok(Some(old_day_id))
+    }
+
+    /// Days left in the catch-up run, including the current one
+    pub fn catch_up_days_left(&self) -> u64 {
+        self.catch_up_through_day.saturating_sub(self.day_id) + 1
     }
 
     /// Check if can crank
@@ -57,9 +227,171 @@ impl DailyProgress {
         self.is_within_window(current_time) && !self.is_finalized
     }
 
+    /// Check if the locked-total snapshot covers every page
+    pub fn is_snapshot_complete(&self) -> bool {
+        count_pages(&self.snapshot_bitmap) >= self.total_pages
+    }
+
+    /// Record a page in the locked-total snapshot, rejecting replays
+    pub fn mark_snapshot_page(&mut self, page_index: u16) -> Result<()> {
+        mark_page(&mut self.snapshot_bitmap, page_index, self.total_pages)?;
+        self.snapshot_pages = count_pages(&self.snapshot_bitmap);
+        Ok(())
+    }
+
+    /// Record a page as paid out, rejecting replays
+    pub fn mark_payout_page(&mut self, page_index: u16) -> Result<()> {
+        mark_page(&mut self.payout_bitmap, page_index, self.total_pages)?;
+        self.current_page = count_pages(&self.payout_bitmap);
+        Ok(())
+    }
+
+    /// Mark every page as paid out (nothing left to distribute to investors)
+    pub fn mark_all_pages_paid(&mut self) {
+        for page_index in 0..self.total_pages {
+            set_page(&mut self.payout_bitmap, page_index);
+        }
+        self.current_page = self.total_pages;
+    }
+
+    /// Check that today's snapshot is complete and payout pages may be cranked
+    pub fn require_payout_ready(&self, day_id: u64, current_time: i64) -> Result<()> {
+        require!(
+            self.covers_day(day_id) && self.is_snapshot_complete(),
+            ErrorCode::SnapshotIncomplete
+        );
+
+        // Check if already finalized
+        require!(!self.is_finalized, ErrorCode::DayAlreadyFinalized);
+
+        // Validate within window
+        require!(
+            self.is_within_window(current_time),
+            ErrorCode::OutsideWindow
+        );
+
+        Ok(())
+    }
+
+    /// Check if a distribution for the given day has started but not finished
+    /// (any snapshot page, fee claim or payout page counts as started)
+    pub fn is_in_progress(&self, day_id: u64) -> bool {
+        self.covers_day(day_id)
+            && !self.is_finalized
+            && (self.snapshot_pages > 0
+                || self.total_quote_claimed_today > 0
+                || self.current_page > 0)
+    }
+
+    /// Fail with `error` if the vault's progress PDA shows a distribution underway
+    /// on `day_id` (no day is in progress until the first crank creates the account)
+    pub fn require_no_day_in_progress(
+        account_info: &AccountInfo,
+        day_id: u64,
+        error: ErrorCode,
+    ) -> Result<()> {
+        if account_info.owner != &crate::ID || account_info.data_is_empty() {
+            return Ok(());
+        }
+
+        let progress = DailyProgress::try_deserialize(&mut &account_info.try_borrow_data()?[..])?;
+        if progress.is_in_progress(day_id) {
+            return Err(error.into());
+        }
+        Ok(())
+    }
+
+    /// Treasury quote that belongs to earlier days: `owed_to_investors` (unclaimed
+    /// credits and carried dust) plus the rolled-over share and unassigned catch-up fees
+    pub fn reserved_carry(&self, owed_to_investors: u64) -> Result<u64> {
+        owed_to_investors
+            .checked_add(self.rollover_lamports)
+            .and_then(|total| total.checked_add(self.catch_up_pool))
+            .ok_or_else(|| ErrorCode::ArithmeticOverflow.into())
+    }
+
+    /// Take the current day's part of newly claimed fees plus the catch-up pool:
+    /// an even split over the days left in the run, with the rounding dust kept
+    /// in the pool so the run's last day receives it
+    pub fn take_day_fees(&mut self, amount_claimed: u64) -> Result<u64> {
+        let catch_up_fees = amount_claimed
+            .checked_add(self.catch_up_pool)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let day_fees = catch_up_fees / self.catch_up_days_left();
+        self.catch_up_pool = catch_up_fees - day_fees;
+        Ok(day_fees)
+    }
+
+    /// Open the day's pool from newly claimed fees: the day's part of the
+    /// catch-up run's fees, plus the share rolled over from earlier days
+    /// Returns the day's fees (excluding the rolled-over share)
+    pub fn open_day_pool(&mut self, amount_claimed: u64) -> Result<u64> {
+        let day_fees = self.take_day_fees(amount_claimed)?;
+
+        self.total_quote_claimed_today = day_fees;
+        self.carry_over_lamports = 0;
+        self.rollover_in_today = self.rollover_lamports;
+        self.rollover_lamports = 0;
+
+        Ok(day_fees)
+    }
+
+    /// Fix the day's investor share: the eligible share plus the rolled-over
+    /// share brought into today, clipped by the daily cap
+    /// With `roll_over`, the clipped amount rolls over while anything is still locked
+    pub fn fix_investor_fee_quote(
+        &mut self,
+        investor_share: u64,
+        daily_cap: Option<u64>,
+        roll_over: bool,
+    ) -> Result<()> {
+        let investor_pool = investor_share
+            .checked_add(self.rollover_in_today)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        self.investor_fee_quote = match daily_cap {
+            Some(cap) => investor_pool.min(cap),
+            None => investor_pool,
+        };
+
+        if roll_over && self.locked_total_snapshot > 0 {
+            self.rollover_lamports = investor_pool - self.investor_fee_quote;
+        }
+
+        Ok(())
+    }
+
+    /// Investor share not yet paid out, carried as dust or spent on transfer fees
+    pub fn investor_share_left(&self) -> u64 {
+        self.investor_fee_quote
+            .saturating_sub(self.investor_distributed_today)
+            .saturating_sub(self.carry_over_lamports)
+            .saturating_sub(self.transfer_fees_today)
+    }
+
+    /// Close the day after its last payout page and return the creator remainder
+    /// With `roll_over`, the investor share left after payouts, carried dust,
+    /// transfer fees and rounding joins the rollover instead of the remainder
+    pub fn close_day(&mut self, roll_over: bool) -> Result<u64> {
+        let undistributed = self.investor_share_left();
+        if roll_over && self.locked_total_snapshot > 0 {
+            self.rollover_lamports = self.rollover_lamports
+                .checked_add(undistributed)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+        }
+
+        // Dust carried into investor balances today and rolled-over share are not the creator's
+        Ok(self.total_quote_claimed_today
+            .saturating_add(self.rollover_in_today)
+            .saturating_sub(self.investor_distributed_today)
+            .saturating_sub(self.carry_over_lamports)
+            .saturating_sub(self.transfer_fees_today)
+            .saturating_sub(self.rollover_lamports))
+    }
+
     /// Check if day is complete
     pub fn is_complete(&self) -> bool {
-        self.current_page >= self.total_pages
+        count_pages(&self.payout_bitmap) >= self.total_pages
     }
 
     /// Reset for new day
@@ -73,5 +405,386 @@ impl DailyProgress {
         self.carry_over_lamports = 0;
         self.current_page = 0;
         self.is_finalized = false;
+        self.snapshot_ts = current_time;
+        self.locked_total_snapshot = 0;
+        self.snapshot_pages = 0;
+        self.snapshot_bitmap = [0u8; PAGE_BITMAP_BYTES];
+        self.payout_bitmap = [0u8; PAGE_BITMAP_BYTES];
+        self.investor_fee_quote = 0;
+        // rollover_lamports stays: it belongs to the new day's investor pool
+        self.rollover_in_today = 0;
+        self.transfer_fees_today = 0;
+        // catch_up_pool stays: it belongs to the remaining days of the run
+        self.catch_up_through_day = day_id;
+    }
+}
+
+fn set_page(bitmap: &mut [u8; PAGE_BITMAP_BYTES], page_index: u16) {
+    bitmap[page_index as usize / 8] |= 1 << (page_index % 8);
+}
+
+fn mark_page(bitmap: &mut [u8; PAGE_BITMAP_BYTES], page_index: u16, total_pages: u16) -> Result<()> {
+    require!(page_index < total_pages, ErrorCode::InvalidRegistryPage);
+
+    let mask = 1u8 << (page_index % 8);
+    require!(
+        bitmap[page_index as usize / 8] & mask == 0,
+        ErrorCode::PageAlreadyProcessed
+    );
+
+    set_page(bitmap, page_index);
+    Ok(())
+}
+
+fn count_pages(bitmap: &[u8; PAGE_BITMAP_BYTES]) -> u16 {
+    bitmap.iter().map(|byte| byte.count_ones() as u16).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_progress(total_pages: u16) -> DailyProgress {
+        let mut progress = DailyProgress {
+            bump: 0,
+            vault: Pubkey::default(),
+            day_id: 0,
+            window_start: 0,
+            last_crank_ts: 0,
+            total_quote_claimed_today: 0,
+            investor_distributed_today: 0,
+            creator_distributed_today: 0,
+            carry_over_lamports: 0,
+            current_page: 0,
+            total_pages: 0,
+            is_finalized: false,
+            snapshot_ts: 0,
+            locked_total_snapshot: 0,
+            snapshot_pages: 0,
+            snapshot_bitmap: [0u8; PAGE_BITMAP_BYTES],
+            payout_bitmap: [0u8; PAGE_BITMAP_BYTES],
+            investor_fee_quote: 0,
+            rollover_lamports: 0,
+            rollover_in_today: 0,
+            catch_up_through_day: 0,
+            catch_up_pool: 0,
+            transfer_fees_today: 0,
+            reserved: [0u8; 8],
+        };
+        progress.reset_for_new_day(1, SECONDS_PER_DAY);
+        progress.total_pages = total_pages;
+        progress
+    }
+
+    #[test]
+    fn test_out_of_order_pages() {
+        let mut progress = create_test_progress(3);
+
+        progress.mark_payout_page(2).unwrap();
+        progress.mark_payout_page(0).unwrap();
+        assert!(!progress.is_complete());
+        assert_eq!(progress.current_page, 2);
+
+        progress.mark_payout_page(1).unwrap();
+        assert!(progress.is_complete());
+    }
+
+    #[test]
+    fn test_page_replay_rejected() {
+        let mut progress = create_test_progress(3);
+
+        progress.mark_snapshot_page(1).unwrap();
+        assert!(progress.mark_snapshot_page(1).is_err());
+        assert_eq!(progress.snapshot_pages, 1);
+    }
+
+    #[test]
+    fn test_page_out_of_range_rejected() {
+        let mut progress = create_test_progress(3);
+
+        assert!(progress.mark_payout_page(3).is_err());
+        assert!(progress.mark_snapshot_page(u16::MAX).is_err());
+    }
+
+    #[test]
+    fn test_mark_all_pages_paid() {
+        let mut progress = create_test_progress(10);
+
+        progress.mark_payout_page(4).unwrap();
+        progress.mark_all_pages_paid();
+        assert!(progress.is_complete());
+        assert_eq!(progress.current_page, 10);
+    }
+
+    #[test]
+    fn test_in_progress_after_snapshot_or_claim() {
+        let mut progress = create_test_progress(2);
+        assert!(!progress.is_in_progress(1));
+
+        // Fees claimed before any payout page was recorded
+        progress.total_quote_claimed_today = 1_000;
+        assert!(progress.is_in_progress(1));
+
+        progress.total_quote_claimed_today = 0;
+        progress.mark_snapshot_page(0).unwrap();
+        assert!(progress.is_in_progress(1));
+        assert!(!progress.is_in_progress(2));
+
+        progress.is_finalized = true;
+        assert!(!progress.is_in_progress(1));
+    }
+
+    #[test]
+    fn test_reserved_carry_includes_rollover_and_catch_up_pool() {
+        let mut progress = create_test_progress(1);
+        assert_eq!(progress.reserved_carry(100).unwrap(), 100);
+
+        progress.rollover_lamports = 20;
+        progress.catch_up_pool = 3;
+        assert_eq!(progress.reserved_carry(100).unwrap(), 123);
+        assert!(progress.reserved_carry(u64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_rollover_after_cap() {
+        let mut progress = create_test_progress(1);
+        progress.locked_total_snapshot = 1_000;
+        progress.total_quote_claimed_today = 1_000;
+
+        // 700 eligible, capped at 500: 200 rolls over
+        progress.fix_investor_fee_quote(700, Some(500), true).unwrap();
+        assert_eq!(progress.investor_fee_quote, 500);
+        assert_eq!(progress.rollover_lamports, 200);
+
+        // 480 paid, 15 carried as dust, 5 lost to rounding: the 5 rolls over too
+        progress.investor_distributed_today = 480;
+        progress.carry_over_lamports = 15;
+        assert_eq!(progress.close_day(true).unwrap(), 300);
+        assert_eq!(progress.rollover_lamports, 205);
+
+        // Next day: the rollover joins the eligible share under the same cap
+        progress.reset_for_new_day(2, 2 * SECONDS_PER_DAY);
+        progress.locked_total_snapshot = 1_000;
+        progress.rollover_in_today = progress.rollover_lamports;
+        progress.rollover_lamports = 0;
+        progress.fix_investor_fee_quote(400, Some(500), true).unwrap();
+        assert_eq!(progress.investor_fee_quote, 500);
+        assert_eq!(progress.rollover_lamports, 105);
+    }
+
+    #[test]
+    fn test_no_rollover_without_option_or_locked_weight() {
+        let mut progress = create_test_progress(1);
+        progress.total_quote_claimed_today = 1_000;
+
+        // Option off: the capped amount goes to the creator
+        progress.locked_total_snapshot = 1_000;
+        progress.fix_investor_fee_quote(700, Some(500), false).unwrap();
+        assert_eq!(progress.rollover_lamports, 0);
+        progress.investor_distributed_today = 500;
+        assert_eq!(progress.close_day(false).unwrap(), 500);
+
+        // Nothing locked: nobody to roll the share over to
+        progress.investor_distributed_today = 0;
+        progress.locked_total_snapshot = 0;
+        progress.fix_investor_fee_quote(0, Some(500), true).unwrap();
+        assert_eq!(progress.rollover_lamports, 0);
+        assert_eq!(progress.close_day(true).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn test_catch_up_split_across_missed_days() {
+        let mut progress = create_test_progress(1);
+        progress.is_finalized = true;
+
+        // Cranking resumes on day 5 after day 1: 1_003 claimed for days 2..=5
+        let now = 5 * SECONDS_PER_DAY;
+        progress.reset_for_new_day(progress.next_day_id(5, true), now);
+        progress.begin_catch_up(5);
+        assert_eq!(progress.take_day_fees(1_003).unwrap(), 250);
+        assert_eq!(progress.catch_up_pool, 753);
+
+        // Later days of the run split what is left; the last day takes the dust
+        let mut day_fees = vec![250];
+        for day in 3..=5 {
+            progress.reset_for_new_day(day, now);
+            progress.begin_catch_up(5);
+            day_fees.push(progress.take_day_fees(0).unwrap());
+        }
+        assert_eq!(day_fees, vec![250, 251, 251, 251]);
+        assert_eq!(day_fees.iter().sum::<u64>(), 1_003);
+        assert_eq!(progress.catch_up_pool, 0);
+    }
+
+    #[test]
+    fn test_advance_day() {
+        let mut progress = create_test_progress(1);
+
+        // The open day is kept until it is settled
+        assert_eq!(progress.advance_day(1, SECONDS_PER_DAY + 10, true).unwrap(), None);
+        progress.is_finalized = true;
+        assert_eq!(progress.advance_day(1, SECONDS_PER_DAY + 10, true).unwrap(), None);
+
+        // The next day starts once its window opens; missed days are caught up
+        assert_eq!(progress.advance_day(4, 4 * SECONDS_PER_DAY, true).unwrap(), Some(1));
+        assert_eq!(progress.day_id, 2);
+        assert_eq!(progress.catch_up_through_day, 4);
+        assert!(!progress.is_finalized);
+
+        // Without catch-up, missed days are skipped
+        let mut progress = create_test_progress(1);
+        progress.is_finalized = true;
+        assert_eq!(progress.advance_day(4, 4 * SECONDS_PER_DAY, false).unwrap(), Some(1));
+        assert_eq!(progress.day_id, 4);
+    }
+
+    #[test]
+    fn test_single_settlement_consumes_rollover_and_catch_up() {
+        // A Merkle root settles the whole day at once
+        let mut progress = create_test_progress(1);
+        progress.rollover_lamports = 300;
+        progress.reset_for_new_day(2, 3 * SECONDS_PER_DAY);
+        progress.begin_catch_up(3);
+
+        // Day 2 of a two-day run takes half of the claimed fees and the rollover
+        assert_eq!(progress.open_day_pool(2_001).unwrap(), 1_000);
+        assert_eq!(progress.catch_up_pool, 1_001);
+        assert_eq!(progress.rollover_in_today, 300);
+        assert_eq!(progress.rollover_lamports, 0);
+
+        // 600 eligible + 300 rolled over, capped at 500: the clip rolls over
+        progress.locked_total_snapshot = 1_000;
+        progress.fix_investor_fee_quote(600, Some(500), true).unwrap();
+        assert_eq!(progress.investor_fee_quote, 500);
+        progress.investor_distributed_today = 500;
+        assert_eq!(progress.close_day(true).unwrap(), 400);
+        assert_eq!(progress.rollover_lamports, 400);
+
+        // Day 3 receives the rest of the run and the rolled-over clip
+        progress.reset_for_new_day(3, 3 * SECONDS_PER_DAY);
+        assert_eq!(progress.open_day_pool(0).unwrap(), 1_001);
+        assert_eq!(progress.catch_up_pool, 0);
+        assert_eq!(progress.rollover_in_today, 400);
+
+        // Without rollover, nothing is held back from the creator
+        progress.locked_total_snapshot = 1_000;
+        progress.fix_investor_fee_quote(600, Some(500), false).unwrap();
+        progress.investor_distributed_today = 500;
+        assert_eq!(progress.close_day(false).unwrap(), 1_001 + 400 - 500);
+        assert_eq!(progress.rollover_lamports, 0);
+    }
+
+    #[test]
+    fn test_catch_up_fees_claimed_mid_run() {
+        let mut progress = create_test_progress(1);
+        progress.reset_for_new_day(2, 4 * SECONDS_PER_DAY);
+        progress.begin_catch_up(4);
+
+        assert_eq!(progress.take_day_fees(10).unwrap(), 3);
+        assert_eq!(progress.catch_up_pool, 7);
+
+        // Fees claimed later in the run join the pool before the split
+        progress.reset_for_new_day(3, 4 * SECONDS_PER_DAY);
+        progress.begin_catch_up(4);
+        assert_eq!(progress.take_day_fees(5).unwrap(), 6);
+        assert_eq!(progress.catch_up_pool, 6);
+
+        progress.reset_for_new_day(4, 4 * SECONDS_PER_DAY);
+        progress.begin_catch_up(4);
+        assert_eq!(progress.take_day_fees(0).unwrap(), 6);
+        assert_eq!(progress.catch_up_pool, 0);
+    }
+
+    #[test]
+    fn test_rollover_survives_day_reset() {
+        let mut progress = create_test_progress(1);
+        progress.rollover_lamports = 500;
+        progress.rollover_in_today = 200;
+
+        progress.reset_for_new_day(2, 2 * SECONDS_PER_DAY);
+        assert_eq!(progress.rollover_lamports, 500);
+        assert_eq!(progress.rollover_in_today, 0);
+    }
+
+    #[test]
+    fn test_catch_up_run() {
+        let mut progress = create_test_progress(1);
+        progress.is_finalized = true;
+
+        // Cranking resumes on day 5 after day 1: days 2..=5 are processed in turn
+        assert_eq!(progress.next_day_id(5, false), 5);
+        let next_day = progress.next_day_id(5, true);
+        assert_eq!(next_day, 2);
+
+        let now = 5 * SECONDS_PER_DAY + 100;
+        progress.reset_for_new_day(next_day, now);
+        progress.begin_catch_up(5);
+        assert_eq!(progress.snapshot_ts, 2 * SECONDS_PER_DAY);
+        assert_eq!(progress.catch_up_days_left(), 4);
+        assert!(progress.covers_day(5));
+        assert!(progress.is_within_window(now));
+        assert!(!progress.is_within_window(6 * SECONDS_PER_DAY));
+
+        // The last day of the run is evaluated normally
+        progress.reset_for_new_day(5, now);
+        progress.begin_catch_up(5);
+        assert_eq!(progress.snapshot_ts, now);
+        assert_eq!(progress.catch_up_days_left(), 1);
+
+        // Runs never reach back further than MAX_CATCH_UP_DAYS
+        assert_eq!(progress.next_day_id(100, true), 100 - MAX_CATCH_UP_DAYS + 1);
+    }
+
+    fn legacy_progress() -> LegacyDailyProgress {
+        LegacyDailyProgress {
+            bump: 253,
+            vault: Pubkey::new_unique(),
+            day_id: 20_000,
+            window_start: 20_000 * SECONDS_PER_DAY,
+            last_crank_ts: 20_000 * SECONDS_PER_DAY + 60,
+            total_quote_claimed_today: 5_000,
+            investor_distributed_today: 1_200,
+            creator_distributed_today: 0,
+            carry_over_lamports: 7,
+            current_page: 2,
+            total_pages: 4,
+            is_finalized: false,
+            reserved: [0u8; 32],
+        }
+    }
+
+    #[test]
+    fn test_legacy_layout_size() {
+        let bytes = legacy_progress().try_to_vec().unwrap();
+        assert_eq!(bytes.len(), LegacyDailyProgress::INIT_SPACE);
+
+        // Old accounts cannot be read with the current layout
+        assert!(DailyProgress::deserialize(&mut &bytes[..]).is_err());
+    }
+
+    #[test]
+    fn test_from_legacy_keeps_paid_pages() {
+        let bytes = legacy_progress().try_to_vec().unwrap();
+        let legacy = LegacyDailyProgress::deserialize(&mut &bytes[..]).unwrap();
+        let mut progress = DailyProgress::from_legacy(legacy.clone());
+
+        assert_eq!(progress.vault, legacy.vault);
+        assert_eq!(progress.day_id, 20_000);
+        assert_eq!(progress.total_quote_claimed_today, 5_000);
+        assert_eq!(progress.investor_distributed_today, 1_200);
+        assert!(progress.covers_day(20_000));
+        assert!(progress.is_in_progress(20_000));
+
+        // Pages 0 and 1 were paid by the legacy crank and cannot be replayed
+        assert_eq!(progress.current_page, 2);
+        assert!(progress.mark_payout_page(1).is_err());
+        progress.mark_payout_page(2).unwrap();
+
+        // The day waits for a snapshot before paying out again
+        assert!(!progress.is_snapshot_complete());
+
+        // The migrated account round-trips at its full size
+        let bytes = progress.try_to_vec().unwrap();
+        assert_eq!(bytes.len(), DailyProgress::INIT_SPACE);
     }
 }
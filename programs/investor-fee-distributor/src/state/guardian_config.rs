@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+/// Program-wide emergency stop, held by a guardian appointed by the upgrade authority
+#[account]
+pub struct GuardianConfig {
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+    /// Wallet allowed to pause and unpause every vault at once
+    pub guardian: Pubkey,
+    /// Whether distributions are paused for all vaults
+    pub paused: bool,
+    /// Reserved for future upgrades
+    pub reserved: [u8; 32],
+}
+
+impl GuardianConfig {
+    pub const INIT_SPACE: usize =
+        1 +     // bump
+        32 +    // guardian
+        1 +     // paused
+        32;     // reserved
+
+    /// Whether the guardian config account pauses all vaults
+    /// (false until the account has been created)
+    pub fn is_global_pause_set(account_info: &AccountInfo) -> Result<bool> {
+        if account_info.owner != &crate::ID || account_info.data_is_empty() {
+            return Ok(false);
+        }
+
+        let config = GuardianConfig::try_deserialize(&mut &account_info.try_borrow_data()?[..])?;
+        Ok(config.paused)
+    }
+}
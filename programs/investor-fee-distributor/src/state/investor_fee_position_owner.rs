@@ -1,4 +1,65 @@
 use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
+
+/// AMM program holding the honorary position
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PositionKind {
+    /// DAMM v1 (dynamic_amm) lock escrow
+    DynamicAmmLockEscrow,
+    /// DAMM v2 (cp-amm) position NFT
+    CpAmmPosition,
+}
+
+/// Pool fee collection mode (mirrors cp-amm `collect_fee_mode`)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CollectFeeMode {
+    /// Fees accrue in both token A and token B
+    BothToken,
+    /// Fees accrue only in token B (quote)
+    OnlyB,
+}
+
+impl CollectFeeMode {
+    pub fn try_from_u8(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(Self::BothToken),
+            1 => Ok(Self::OnlyB),
+            _ => Err(ErrorCode::InvalidPool.into()),
+        }
+    }
+}
+
+/// InvestorFeePositionOwner layout written by the first program release,
+/// before cp-amm positions and the treasury ledger were added
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct LegacyInvestorFeePositionOwner {
+    pub bump: u8,
+    pub vault: Pubkey,
+    pub pool: Pubkey,
+    pub lock_escrow: Pubkey,
+    pub lp_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub base_mint: Pubkey,
+    pub created_at: i64,
+    pub last_fee_claim: i64,
+    pub total_fees_claimed: u64,
+    pub reserved: [u8; 64],
+}
+
+impl LegacyInvestorFeePositionOwner {
+    pub const INIT_SPACE: usize =
+        1 +     // bump
+        32 +    // vault
+        32 +    // pool
+        32 +    // lock_escrow
+        32 +    // lp_mint
+        32 +    // quote_mint
+        32 +    // base_mint
+        8 +     // created_at
+        8 +     // last_fee_claim
+        8 +     // total_fees_claimed
+        64;     // reserved
+}
 
 /// PDA that owns the honorary DAMM v2 position for fee collection
 #[account]
@@ -9,9 +70,9 @@ pub struct InvestorFeePositionOwner {
     pub vault: Pubkey,
     /// DAMM v2 pool address
     pub pool: Pubkey,
-    /// Lock escrow (honorary position in DAMM v2)
-    pub lock_escrow: Pubkey,
-    /// LP mint of the pool
+    /// Honorary position: lock escrow (DAMM v1) or position account (cp-amm)
+    pub position: Pubkey,
+    /// LP mint of the pool (DAMM v1 only)
     pub lp_mint: Pubkey,
     /// Quote mint (Token B in DAMM v2)
     pub quote_mint: Pubkey,
@@ -23,8 +84,24 @@ pub struct InvestorFeePositionOwner {
     pub last_fee_claim: i64,
     /// Lifetime total quote fees claimed
     pub total_fees_claimed: u64,
+    /// AMM program holding the honorary position
+    pub position_kind: PositionKind,
+    /// Position NFT mint (cp-amm only)
+    pub position_nft_mint: Pubkey,
+    /// Pool fee collection mode validated at initialization
+    pub collect_fee_mode: CollectFeeMode,
+    /// Quote credited to investor ledgers but not yet claimed (held in the treasury)
+    pub pending_claims: u64,
+    /// Dust carried for investors below the payout threshold (held in the treasury)
+    pub carried_dust: u64,
+    /// Treasury quote balance just before the latest fee claim
+    pub balance_before_claim: u64,
+    /// Quote received by the latest fee claim (that day's fee pool)
+    pub last_claimed_delta: u64,
+    /// Treasury quote reserved for earlier days at the latest fee claim
+    pub reserved_carry: u64,
     /// Reserved for future upgrades
-    pub reserved: [u8; 64],
+    pub reserved: [u8; 14],
 }
 
 impl InvestorFeePositionOwner {
@@ -32,12 +109,220 @@ impl InvestorFeePositionOwner {
         1 +     // bump
         32 +    // vault
         32 +    // pool
-        32 +    // lock_escrow
+        32 +    // position
         32 +    // lp_mint
         32 +    // quote_mint
         32 +    // base_mint
         8 +     // created_at
         8 +     // last_fee_claim
         8 +     // total_fees_claimed
-        64;     // reserved
+        1 +     // position_kind
+        32 +    // position_nft_mint
+        1 +     // collect_fee_mode
+        8 +     // pending_claims
+        8 +     // carried_dust
+        8 +     // balance_before_claim
+        8 +     // last_claimed_delta
+        8 +     // reserved_carry
+        14;     // reserved
+
+    /// Carry a legacy position owner over to the current layout
+    /// The first release only created DAMM v1 lock escrows, and the legacy
+    /// crank paid every credit out in the same transaction, so the treasury
+    /// ledger starts empty
+    pub fn from_legacy(legacy: LegacyInvestorFeePositionOwner) -> Self {
+        Self {
+            bump: legacy.bump,
+            vault: legacy.vault,
+            pool: legacy.pool,
+            position: legacy.lock_escrow,
+            lp_mint: legacy.lp_mint,
+            quote_mint: legacy.quote_mint,
+            base_mint: legacy.base_mint,
+            created_at: legacy.created_at,
+            last_fee_claim: legacy.last_fee_claim,
+            total_fees_claimed: legacy.total_fees_claimed,
+            position_kind: PositionKind::DynamicAmmLockEscrow,
+            position_nft_mint: Pubkey::default(),
+            collect_fee_mode: CollectFeeMode::BothToken,
+            pending_claims: 0,
+            carried_dust: 0,
+            balance_before_claim: 0,
+            last_claimed_delta: 0,
+            reserved_carry: 0,
+            reserved: [0u8; 14],
+        }
+    }
+
+    /// Whether the vault's position owner account holds a position that accrues
+    /// base fees (a DAMM v1 lock escrow); false until the position is created
+    pub fn collects_base_fees(account_info: &AccountInfo) -> Result<bool> {
+        if account_info.owner != &crate::ID || account_info.data_is_empty() {
+            return Ok(false);
+        }
+
+        let owner = InvestorFeePositionOwner::try_deserialize(&mut &account_info.try_borrow_data()?[..])?;
+        Ok(owner.collect_fee_mode == CollectFeeMode::BothToken)
+    }
+
+    /// Treasury quote already owed to investors, excluded from new fees
+    pub fn owed_to_investors(&self) -> Result<u64> {
+        self.pending_claims
+            .checked_add(self.carried_dust)
+            .ok_or_else(|| ErrorCode::ArithmeticOverflow.into())
+    }
+
+    /// Record a fee claim in the treasury ledger and return the claimed delta
+    pub fn record_fee_claim(
+        &mut self,
+        balance_before: u64,
+        balance_after: u64,
+        reserved_carry: u64,
+        current_time: i64,
+    ) -> Result<u64> {
+        let claimed = balance_after
+            .checked_sub(balance_before)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+        self.balance_before_claim = balance_before;
+        self.last_claimed_delta = claimed;
+        self.reserved_carry = reserved_carry;
+        self.last_fee_claim = current_time;
+        self.total_fees_claimed = self.total_fees_claimed
+            .checked_add(claimed)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        Ok(claimed)
+    }
+
+    /// Drop carried dust once it is paid out or released to the fee pool
+    pub fn remove_carried_dust(&mut self, amount: u64) -> Result<()> {
+        self.carried_dust = self.carried_dust
+            .checked_sub(amount)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_owner(collect_fee_mode: CollectFeeMode) -> InvestorFeePositionOwner {
+        InvestorFeePositionOwner {
+            bump: 0,
+            vault: Pubkey::default(),
+            pool: Pubkey::default(),
+            position: Pubkey::default(),
+            lp_mint: Pubkey::default(),
+            quote_mint: Pubkey::default(),
+            base_mint: Pubkey::default(),
+            created_at: 0,
+            last_fee_claim: 0,
+            total_fees_claimed: 0,
+            position_kind: PositionKind::DynamicAmmLockEscrow,
+            position_nft_mint: Pubkey::default(),
+            collect_fee_mode,
+            pending_claims: 0,
+            carried_dust: 0,
+            balance_before_claim: 0,
+            last_claimed_delta: 0,
+            reserved_carry: 0,
+            reserved: [0u8; 14],
+        }
+    }
+
+    fn collects_base_fees(owner: Option<&InvestorFeePositionOwner>, program: &Pubkey) -> bool {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = Vec::new();
+        if let Some(owner) = owner {
+            owner.try_serialize(&mut data).unwrap();
+        }
+        let info = AccountInfo::new(&key, false, false, &mut lamports, &mut data, program, false, 0);
+        InvestorFeePositionOwner::collects_base_fees(&info).unwrap()
+    }
+
+    #[test]
+    fn test_collects_base_fees() {
+        // No position yet
+        assert!(!collects_base_fees(None, &Pubkey::default()));
+
+        // DAMM v1 lock escrows accrue base fees, cp-amm OnlyB positions do not
+        let damm_v1 = create_test_owner(CollectFeeMode::BothToken);
+        assert!(collects_base_fees(Some(&damm_v1), &crate::ID));
+        let cp_amm = create_test_owner(CollectFeeMode::OnlyB);
+        assert!(!collects_base_fees(Some(&cp_amm), &crate::ID));
+    }
+
+    #[test]
+    fn test_record_fee_claim_counts_only_delta() {
+        let mut owner = InvestorFeePositionOwner {
+            bump: 0,
+            vault: Pubkey::default(),
+            pool: Pubkey::default(),
+            position: Pubkey::default(),
+            lp_mint: Pubkey::default(),
+            quote_mint: Pubkey::default(),
+            base_mint: Pubkey::default(),
+            created_at: 0,
+            last_fee_claim: 0,
+            total_fees_claimed: 0,
+            position_kind: PositionKind::CpAmmPosition,
+            position_nft_mint: Pubkey::default(),
+            collect_fee_mode: CollectFeeMode::OnlyB,
+            pending_claims: 300,
+            carried_dust: 50,
+            balance_before_claim: 0,
+            last_claimed_delta: 0,
+            reserved_carry: 0,
+            reserved: [0u8; 14],
+        };
+
+        // Leftover balance before the claim is not today's fees
+        let reserved = owner.owed_to_investors().unwrap();
+        assert_eq!(owner.record_fee_claim(1_000, 1_600, reserved, 42).unwrap(), 600);
+        assert_eq!(owner.balance_before_claim, 1_000);
+        assert_eq!(owner.reserved_carry, 350);
+        assert_eq!(owner.total_fees_claimed, 600);
+        assert_eq!(owner.last_fee_claim, 42);
+
+        assert!(owner.record_fee_claim(1_600, 1_000, reserved, 43).is_err());
+    }
+
+    #[test]
+    fn test_from_legacy_keeps_lock_escrow() {
+        let legacy = LegacyInvestorFeePositionOwner {
+            bump: 252,
+            vault: Pubkey::new_unique(),
+            pool: Pubkey::new_unique(),
+            lock_escrow: Pubkey::new_unique(),
+            lp_mint: Pubkey::new_unique(),
+            quote_mint: Pubkey::new_unique(),
+            base_mint: Pubkey::new_unique(),
+            created_at: 1_700_000_000,
+            last_fee_claim: 1_700_086_400,
+            total_fees_claimed: 9_000,
+            reserved: [0u8; 64],
+        };
+        let bytes = legacy.try_to_vec().unwrap();
+        assert_eq!(bytes.len(), LegacyInvestorFeePositionOwner::INIT_SPACE);
+
+        // Old accounts cannot be read with the current layout
+        assert!(InvestorFeePositionOwner::deserialize(&mut &bytes[..]).is_err());
+
+        let legacy = LegacyInvestorFeePositionOwner::deserialize(&mut &bytes[..]).unwrap();
+        let owner = InvestorFeePositionOwner::from_legacy(legacy.clone());
+        assert_eq!(owner.vault, legacy.vault);
+        assert_eq!(owner.pool, legacy.pool);
+        assert_eq!(owner.position, legacy.lock_escrow);
+        assert_eq!(owner.quote_mint, legacy.quote_mint);
+        assert_eq!(owner.total_fees_claimed, 9_000);
+        assert_eq!(owner.position_kind, PositionKind::DynamicAmmLockEscrow);
+        assert_eq!(owner.owed_to_investors().unwrap(), 0);
+
+        // The migrated account round-trips at its full size
+        let bytes = owner.try_to_vec().unwrap();
+        assert_eq!(bytes.len(), InvestorFeePositionOwner::INIT_SPACE);
+    }
 }
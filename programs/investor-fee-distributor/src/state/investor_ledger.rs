@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+/// Per-investor claimable balance, credited by the crank in pull distribution mode
+#[account]
+pub struct InvestorLedger {
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+    /// Vault this ledger serves
+    pub vault: Pubkey,
+    /// Registry slot account (vesting account or investor wallet) this ledger tracks
+    pub vesting_account: Pubkey,
+    /// Investor wallet that owns the claimable balance
+    pub owner: Pubkey,
+    /// Optional wallet allowed to claim to the owner's token accounts (default if none)
+    pub delegate: Pubkey,
+    /// Lifetime quote credited by the crank
+    pub accrued: u64,
+    /// Lifetime quote withdrawn via claim_fees
+    pub claimed: u64,
+    /// Day of the most recent credit
+    pub last_day: u64,
+    /// Reserved for future upgrades
+    pub reserved: [u8; 32],
+}
+
+impl InvestorLedger {
+    pub const INIT_SPACE: usize =
+        1 +     // bump
+        32 +    // vault
+        32 +    // vesting_account
+        32 +    // owner
+        32 +    // delegate
+        8 +     // accrued
+        8 +     // claimed
+        8 +     // last_day
+        32;     // reserved
+
+    /// Quote credited but not yet claimed
+    pub fn claimable(&self) -> u64 {
+        self.accrued.saturating_sub(self.claimed)
+    }
+
+    /// Whether a signer may claim this ledger's balance
+    pub fn is_claimant(&self, signer: &Pubkey) -> bool {
+        *signer == self.owner || (self.delegate != Pubkey::default() && *signer == self.delegate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_claimant_and_claimable() {
+        let owner = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        let mut ledger = InvestorLedger {
+            bump: 0,
+            vault: Pubkey::default(),
+            vesting_account: Pubkey::default(),
+            owner,
+            delegate: Pubkey::default(),
+            accrued: 500,
+            claimed: 200,
+            last_day: 0,
+            reserved: [0u8; 32],
+        };
+
+        assert_eq!(ledger.claimable(), 300);
+        assert!(ledger.is_claimant(&owner));
+        assert!(!ledger.is_claimant(&delegate));
+        // An unset delegate never matches the default key
+        assert!(!ledger.is_claimant(&Pubkey::default()));
+
+        ledger.delegate = delegate;
+        assert!(ledger.is_claimant(&delegate));
+    }
+}
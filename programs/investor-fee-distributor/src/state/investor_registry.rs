@@ -0,0 +1,195 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::ErrorCode};
+
+/// Single investor registered for fee distribution
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvestorEntry {
+    /// Vesting account holding the investor's locked tokens
+    /// (the investor wallet in static table weight mode)
+    pub stream: Pubkey,
+    /// Investor's quote token ATA receiving payouts
+    pub quote_ata: Pubkey,
+}
+
+impl InvestorEntry {
+    pub const SIZE: usize =
+        32 +    // stream
+        32;     // quote_ata
+}
+
+/// One page of the on-chain investor registry, managed by the policy authority
+#[account]
+pub struct InvestorRegistryPage {
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+    /// Vault this registry page serves
+    pub vault: Pubkey,
+    /// Page index (0-indexed)
+    pub page_index: u16,
+    /// Investors on this page, in crank order (ascending by stream)
+    pub entries: Vec<InvestorEntry>,
+    /// Dust owed to each entry (same order), held in the treasury until it
+    /// crosses min_payout_lamports
+    pub carried: Vec<u64>,
+}
+
+impl InvestorRegistryPage {
+    pub const INIT_SPACE: usize =
+        1 +     // bump
+        32 +    // vault
+        2 +     // page_index
+        4 + MAX_INVESTORS_PER_PAGE * InvestorEntry::SIZE + // entries
+        4 + MAX_INVESTORS_PER_PAGE * 8; // carried
+
+    /// Check that streams are strictly ascending, which also rules out duplicates
+    pub fn validate_entries(entries: &[InvestorEntry]) -> Result<()> {
+        for pair in entries.windows(2) {
+            require!(pair[0].stream != pair[1].stream, ErrorCode::DuplicateRegistryEntry);
+            require!(pair[0].stream < pair[1].stream, ErrorCode::UnsortedRegistry);
+        }
+        Ok(())
+    }
+
+    /// Check that this page's streams all sort after the previous page's, so a
+    /// fully snapshotted registry cannot list a stream on two pages
+    pub fn require_follows(&self, previous: Option<&InvestorRegistryPage>) -> Result<()> {
+        Self::validate_entries(&self.entries)?;
+
+        if self.page_index == 0 {
+            return Ok(());
+        }
+
+        let previous = previous.ok_or(ErrorCode::RegistryPageMismatch)?;
+        require!(
+            previous.page_index + 1 == self.page_index,
+            ErrorCode::RegistryPageMismatch
+        );
+
+        if let (Some(last), Some(first)) = (previous.entries.last(), self.entries.first()) {
+            require!(last.stream < first.stream, ErrorCode::UnsortedRegistry);
+        }
+
+        Ok(())
+    }
+
+    /// Replace the page's entries, keeping carried dust of investors that stay
+    /// Returns the removed investors that still carry dust, with the amount owed
+    pub fn replace_entries(&mut self, entries: Vec<InvestorEntry>) -> Vec<(InvestorEntry, u64)> {
+        let mut carried = vec![0u64; entries.len()];
+        let mut removed = Vec::new();
+
+        for (old_entry, old_carried) in self.entries.iter().zip(self.carried.iter()) {
+            match entries.iter().position(|entry| entry.stream == old_entry.stream) {
+                Some(i) => carried[i] = *old_carried,
+                None if *old_carried > 0 => removed.push((*old_entry, *old_carried)),
+                None => {}
+            }
+        }
+
+        self.entries = entries;
+        self.carried = carried;
+        removed
+    }
+
+    /// Investors on this page that carry dust, with the amount owed
+    pub fn carried_entries(&self) -> Vec<(InvestorEntry, u64)> {
+        self.entries
+            .iter()
+            .zip(self.carried.iter())
+            .filter(|(_, carried)| **carried > 0)
+            .map(|(entry, carried)| (*entry, *carried))
+            .collect()
+    }
+
+    /// Verify that the supplied stream accounts match this page, in order
+    pub fn verify_streams(&self, stream_accounts: &[AccountInfo]) -> Result<()> {
+        require!(
+            stream_accounts.len() == self.entries.len(),
+            ErrorCode::RegistryPageMismatch
+        );
+
+        for (entry, account) in self.entries.iter().zip(stream_accounts.iter()) {
+            require_keys_eq!(entry.stream, account.key(), ErrorCode::RegistryPageMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// Verify that the supplied payout accounts match this page, in order
+    pub fn verify_payout_accounts(&self, ata_accounts: &[AccountInfo]) -> Result<()> {
+        require!(
+            ata_accounts.len() == self.entries.len(),
+            ErrorCode::RegistryPageMismatch
+        );
+
+        for (entry, account) in self.entries.iter().zip(ata_accounts.iter()) {
+            require_keys_eq!(entry.quote_ata, account.key(), ErrorCode::RegistryPageMismatch);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry() -> InvestorEntry {
+        InvestorEntry {
+            stream: Pubkey::new_unique(),
+            quote_ata: Pubkey::new_unique(),
+        }
+    }
+
+    fn page(page_index: u16, entries: Vec<InvestorEntry>) -> InvestorRegistryPage {
+        InvestorRegistryPage {
+            bump: 0,
+            vault: Pubkey::default(),
+            page_index,
+            carried: vec![0; entries.len()],
+            entries,
+        }
+    }
+
+    #[test]
+    fn test_replace_entries_keeps_carried_dust() {
+        let (a, b, c) = (entry(), entry(), entry());
+        let mut page = page(0, vec![]);
+
+        assert!(page.replace_entries(vec![a, b]).is_empty());
+        assert_eq!(page.carried, vec![0, 0]);
+        page.carried = vec![40, 70];
+
+        // b keeps its dust at its new position, a is owed its dust
+        assert_eq!(page.replace_entries(vec![c, b]), vec![(a, 40)]);
+        assert_eq!(page.carried, vec![0, 70]);
+        assert_eq!(page.carried_entries(), vec![(b, 70)]);
+    }
+
+    #[test]
+    fn test_validate_entries_requires_ascending_streams() {
+        // Pubkey::new_unique hands out increasing keys
+        let (a, b, c) = (entry(), entry(), entry());
+
+        assert!(InvestorRegistryPage::validate_entries(&[a, b, c]).is_ok());
+        assert!(InvestorRegistryPage::validate_entries(&[a, c, b]).is_err());
+        assert!(InvestorRegistryPage::validate_entries(&[a, b, b]).is_err());
+    }
+
+    #[test]
+    fn test_stream_repeated_across_pages_rejected() {
+        let (a, b, c, d) = (entry(), entry(), entry(), entry());
+        let first = page(0, vec![a, b]);
+
+        assert!(first.require_follows(None).is_ok());
+        assert!(page(1, vec![c, d]).require_follows(Some(&first)).is_ok());
+
+        // b is already on page 0
+        assert!(page(1, vec![b, c]).require_follows(Some(&first)).is_err());
+        assert!(page(1, vec![a, d]).require_follows(Some(&first)).is_err());
+
+        // Later pages must be checked against the page right before them
+        assert!(page(1, vec![c, d]).require_follows(None).is_err());
+        assert!(page(2, vec![c, d]).require_follows(Some(&first)).is_err());
+    }
+}
@@ -1,7 +1,17 @@
+pub mod daily_distribution;
 pub mod daily_progress;
+pub mod guardian_config;
 pub mod investor_fee_position_owner;
+pub mod investor_ledger;
+pub mod investor_registry;
 pub mod policy_config;
+pub mod weight_table;
 
+pub use daily_distribution::*;
 pub use daily_progress::*;
+pub use guardian_config::*;
 pub use investor_fee_position_owner::*;
+pub use investor_ledger::*;
+pub use investor_registry::*;
 pub use policy_config::*;
+pub use weight_table::*;
@@ -1,4 +1,86 @@
 use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::ErrorCode,
+    state::GuardianConfig,
+    utils::{apply_bps, calculate_investor_fee_quote, VestingSource},
+};
+
+/// Vesting account layouts the distributor can decode
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VestingProgramKind {
+    /// Streamflow stream (`Contract`) accounts
+    Streamflow,
+    /// Jupiter Lock `VestingEscrow` accounts
+    JupiterLock,
+    /// Bonfida token-vesting schedule accounts
+    BonfidaVesting,
+    /// In-house single-unlock escrow accounts
+    CustomEscrow,
+}
+
+/// Source of the per-investor weights used for pro-rata payouts
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WeightMode {
+    /// Amounts still locked in vesting accounts at the day's snapshot
+    VestingLocked,
+    /// Static weights published by the authority in the WeightTable
+    StaticTable,
+}
+
+/// How the crank hands out the investor share
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DistributionMode {
+    /// Transfer payouts to investor ATAs during the crank
+    Push,
+    /// Credit InvestorLedger PDAs; investors withdraw with claim_fees
+    Pull,
+    /// Post a daily Merkle root; investors claim with proofs
+    Merkle,
+}
+
+/// Vesting program accepted by a policy, with the decoder used for its accounts
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VestingProgram {
+    /// Decoder for accounts owned by this program
+    pub kind: VestingProgramKind,
+    /// Program that must own the vesting accounts
+    pub program_id: Pubkey,
+}
+
+impl VestingProgram {
+    pub const SIZE: usize =
+        1 +     // kind
+        32;     // program_id
+}
+
+/// PolicyConfig layout written by the first program release, before the
+/// registry, vesting filter and distribution options were added
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct LegacyPolicyConfig {
+    pub bump: u8,
+    pub authority: Pubkey,
+    pub vault: Pubkey,
+    pub investor_fee_share_bps: u16,
+    pub daily_cap_lamports: Option<u64>,
+    pub min_payout_lamports: u64,
+    pub y0_total_streamed: u64,
+    pub creator_quote_ata: Pubkey,
+    pub reserved: [u8; 64],
+}
+
+impl LegacyPolicyConfig {
+    pub const INIT_SPACE: usize =
+        1 +     // bump
+        32 +    // authority
+        32 +    // vault
+        2 +     // investor_fee_share_bps
+        1 + 8 + // Option<u64> for daily_cap_lamports
+        8 +     // min_payout_lamports
+        8 +     // y0_total_streamed
+        32 +    // creator_quote_ata
+        64;     // reserved
+}
 
 /// Configuration for fee distribution policy
 #[account]
@@ -19,8 +101,49 @@ pub struct PolicyConfig {
     pub y0_total_streamed: u64,
     /// Creator's quote token ATA for receiving remainder
     pub creator_quote_ata: Pubkey,
+    /// Proposed new authority awaiting acceptance (default if none)
+    pub pending_authority: Pubkey,
+    /// Number of investor registry pages (one crank page each)
+    pub registry_pages: u16,
+    /// Mint that counted streams must vest (default until the vesting filter is set)
+    pub vesting_mint: Pubkey,
+    /// Stream senders whose streams count toward locked totals (empty to
+    /// accept only vesting accounts that record no sender, e.g. Bonfida)
+    pub allowed_senders: Vec<Pubkey>,
+    /// Vesting programs whose accounts count toward locked totals
+    pub vesting_programs: Vec<VestingProgram>,
+    /// Where investor weights come from
+    pub weight_mode: WeightMode,
+    /// Push transfers or pull-based ledger credits
+    pub distribution_mode: DistributionMode,
+    /// Roll capped and undistributed investor share into the next day's pool
+    /// instead of paying it to the creator
+    pub roll_over_undistributed: bool,
+    /// Process missed days one by one, splitting their fees evenly and
+    /// evaluating locked amounts at each day's window start
+    pub catch_up_missed_days: bool,
+    /// Pay push payouts and the creator remainder as lamports when the quote
+    /// mint is wrapped SOL
+    pub pay_native_sol: bool,
+    /// Base token account that sweep_base_fees sends base fees to (default if unset)
+    pub base_fee_destination: Pubkey,
+    /// Let cranks proceed while base fees sit untouched in the treasury
+    /// instead of failing with BaseFeesDetected
+    pub quarantine_base_fees: bool,
+    /// Swap treasury base fees into quote on the honorary DAMM v1 pool before
+    /// distribution
+    pub swap_base_fees: bool,
+    /// Maximum shortfall of a base fee swap against the pool's spot price
+    pub max_swap_slippage_bps: u16,
+    /// Smallest base balance worth swapping
+    pub min_base_swap_amount: u64,
+    /// Emergency stop for this vault's distributions
+    pub paused: bool,
+    /// Authority-set floor for base fee swaps, in quote atoms per SWAP_PRICE_SCALE
+    /// base atoms (independent of pool reserves, which can be moved in the same slot)
+    pub min_swap_price: u64,
     /// Reserved for future upgrades
-    pub reserved: [u8; 64],
+    pub reserved: [u8; 5],
 }
 
 impl PolicyConfig {
@@ -33,5 +156,252 @@ impl PolicyConfig {
         8 +     // min_payout_lamports
         8 +     // y0_total_streamed
         32 +    // creator_quote_ata
-        64;     // reserved
+        32 +    // pending_authority
+        2 +     // registry_pages
+        32 +    // vesting_mint
+        4 + MAX_ALLOWED_SENDERS * 32 + // allowed_senders
+        4 + MAX_VESTING_PROGRAMS * VestingProgram::SIZE + // vesting_programs
+        1 +     // weight_mode
+        1 +     // distribution_mode
+        1 +     // roll_over_undistributed
+        1 +     // catch_up_missed_days
+        1 +     // pay_native_sol
+        32 +    // base_fee_destination
+        1 +     // quarantine_base_fees
+        1 +     // swap_base_fees
+        2 +     // max_swap_slippage_bps
+        8 +     // min_base_swap_amount
+        1 +     // paused
+        8 +     // min_swap_price
+        5;      // reserved
+
+    /// Carry a legacy policy over to the current layout, with every option
+    /// added since at its initialize_policy default
+    pub fn from_legacy(legacy: LegacyPolicyConfig) -> Self {
+        Self {
+            bump: legacy.bump,
+            authority: legacy.authority,
+            vault: legacy.vault,
+            investor_fee_share_bps: legacy.investor_fee_share_bps,
+            daily_cap_lamports: legacy.daily_cap_lamports,
+            min_payout_lamports: legacy.min_payout_lamports,
+            y0_total_streamed: legacy.y0_total_streamed,
+            creator_quote_ata: legacy.creator_quote_ata,
+            pending_authority: Pubkey::default(),
+            registry_pages: 0,
+            vesting_mint: Pubkey::default(),
+            allowed_senders: Vec::new(),
+            vesting_programs: Vec::new(),
+            weight_mode: WeightMode::VestingLocked,
+            distribution_mode: DistributionMode::Push,
+            roll_over_undistributed: false,
+            catch_up_missed_days: false,
+            pay_native_sol: false,
+            base_fee_destination: Pubkey::default(),
+            quarantine_base_fees: false,
+            swap_base_fees: false,
+            max_swap_slippage_bps: 0,
+            min_base_swap_amount: 0,
+            paused: false,
+            min_swap_price: 0,
+            reserved: [0u8; 5],
+        }
+    }
+
+    /// Validate policy parameters shared by initialization and updates
+    pub fn validate_params(investor_fee_share_bps: u16, y0_total_streamed: u64) -> Result<()> {
+        require!(
+            investor_fee_share_bps <= MAX_BPS,
+            ErrorCode::InvalidFeeShareBps
+        );
+        require!(y0_total_streamed > 0, ErrorCode::InvalidY0Amount);
+        Ok(())
+    }
+
+    /// Fail if this vault or, through the guardian config, every vault is paused
+    pub fn require_not_paused(&self, guardian_config: &AccountInfo) -> Result<()> {
+        require!(
+            !self.paused && !GuardianConfig::is_global_pause_set(guardian_config)?,
+            ErrorCode::DistributionPaused
+        );
+        Ok(())
+    }
+
+    /// Investor share of a day's fees before the daily cap
+    /// Locked vesting amounts scale the share by the fraction of Y0 still locked;
+    /// static weights have no token units, so they only split the full share
+    pub fn investor_share(&self, quote_fees: u64, total_weight: u64) -> Result<u64> {
+        match self.weight_mode {
+            WeightMode::VestingLocked => calculate_investor_fee_quote(
+                quote_fees,
+                total_weight,
+                self.y0_total_streamed,
+                self.investor_fee_share_bps,
+                None,
+            ),
+            WeightMode::StaticTable if total_weight == 0 => Ok(0),
+            WeightMode::StaticTable => apply_bps(quote_fees, self.investor_fee_share_bps),
+        }
+    }
+
+    /// Whether base fees collected alongside quote fees are swapped into quote
+    /// or quarantined for sweeping, instead of blocking distributions
+    pub fn handles_base_fees(&self) -> bool {
+        self.swap_base_fees || self.quarantine_base_fees
+    }
+
+    /// Validate a vesting filter before it is stored
+    pub fn validate_vesting_filter(
+        vesting_mint: &Pubkey,
+        allowed_senders: &[Pubkey],
+        vesting_programs: &[VestingProgram],
+    ) -> Result<()> {
+        require!(
+            *vesting_mint != Pubkey::default()
+                && allowed_senders.len() <= MAX_ALLOWED_SENDERS
+                && !vesting_programs.is_empty()
+                && vesting_programs.len() <= MAX_VESTING_PROGRAMS,
+            ErrorCode::InvalidVestingFilter
+        );
+        for (i, sender) in allowed_senders.iter().enumerate() {
+            require!(
+                !allowed_senders[..i].contains(sender),
+                ErrorCode::InvalidVestingFilter
+            );
+        }
+        // Each owner must map to exactly one decoder
+        for (i, program) in vesting_programs.iter().enumerate() {
+            require!(
+                vesting_programs[..i].iter().all(|other| other.program_id != program.program_id),
+                ErrorCode::InvalidVestingFilter
+            );
+        }
+        Ok(())
+    }
+
+    /// Find the accepted vesting program owning an account
+    pub fn vesting_program_for(&self, owner: &Pubkey) -> Result<VestingProgram> {
+        require!(
+            self.vesting_mint != Pubkey::default(),
+            ErrorCode::VestingFilterNotSet
+        );
+        self.vesting_programs
+            .iter()
+            .find(|program| program.program_id == *owner)
+            .copied()
+            .ok_or_else(|| ErrorCode::VestingProgramNotAccepted.into())
+    }
+
+    /// Require a vesting account to vest the expected mint and come from an allowed sender
+    /// Sources that do not record a sender (Bonfida) are bound by mint and owner program only
+    pub fn verify_vesting_source(&self, source: &dyn VestingSource) -> Result<()> {
+        require!(
+            self.vesting_mint != Pubkey::default(),
+            ErrorCode::VestingFilterNotSet
+        );
+        require_keys_eq!(source.mint(), self.vesting_mint, ErrorCode::StreamMintMismatch);
+        // Sources that record no sender only pass a filter without senders
+        let sender_allowed = match source.sender() {
+            Some(sender) => self.allowed_senders.contains(&sender),
+            None => self.allowed_senders.is_empty(),
+        };
+        require!(sender_allowed, ErrorCode::StreamSenderNotAllowed);
+        Ok(())
+    }
+
+    /// Check if an authority transfer is pending
+    pub fn has_pending_authority(&self) -> bool {
+        self.pending_authority != Pubkey::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn legacy_policy() -> LegacyPolicyConfig {
+        LegacyPolicyConfig {
+            bump: 254,
+            authority: Pubkey::new_unique(),
+            vault: Pubkey::new_unique(),
+            investor_fee_share_bps: 7000,
+            daily_cap_lamports: Some(1_000_000),
+            min_payout_lamports: 1_000,
+            y0_total_streamed: 10_000_000,
+            creator_quote_ata: Pubkey::new_unique(),
+            reserved: [0u8; 64],
+        }
+    }
+
+    #[test]
+    fn test_legacy_layout_size() {
+        // Option<u64> is serialized with its full payload only when Some
+        let bytes = legacy_policy().try_to_vec().unwrap();
+        assert_eq!(bytes.len(), LegacyPolicyConfig::INIT_SPACE);
+    }
+
+    #[test]
+    fn test_from_legacy_keeps_policy_parameters() {
+        let legacy = legacy_policy();
+        let policy = PolicyConfig::from_legacy(legacy.clone());
+
+        assert_eq!(policy.authority, legacy.authority);
+        assert_eq!(policy.vault, legacy.vault);
+        assert_eq!(policy.investor_fee_share_bps, 7000);
+        assert_eq!(policy.daily_cap_lamports, Some(1_000_000));
+        assert_eq!(policy.creator_quote_ata, legacy.creator_quote_ata);
+        assert_eq!(policy.distribution_mode, DistributionMode::Push);
+        assert!(!policy.paused);
+    }
+
+    #[test]
+    fn test_investor_share_by_weight_mode() {
+        let mut policy = PolicyConfig::from_legacy(legacy_policy());
+
+        // Vesting-locked: share scales with locked / Y0, capped at 70%
+        assert_eq!(policy.investor_share(1_000_000, 0).unwrap(), 0);
+        assert_eq!(policy.investor_share(1_000_000, 2_500_000).unwrap(), 250_000);
+        assert_eq!(policy.investor_share(1_000_000, 10_000_000).unwrap(), 700_000);
+
+        // Static table: the full 70% whatever the weights add up to
+        policy.weight_mode = WeightMode::StaticTable;
+        assert_eq!(policy.investor_share(1_000_000, 0).unwrap(), 0);
+        assert_eq!(policy.investor_share(1_000_000, 1).unwrap(), 700_000);
+        assert_eq!(policy.investor_share(1_000_000, u64::MAX).unwrap(), 700_000);
+    }
+
+    #[test]
+    fn test_senderless_source_needs_senderless_filter() {
+        // Bonfida schedule: destination, mint, initialized flag, one release
+        let mut data = vec![0u8; 65];
+        data[32..64].copy_from_slice(&[2u8; 32]);
+        data[64] = 1;
+        data.extend_from_slice(&1000u64.to_le_bytes());
+        data.extend_from_slice(&100u64.to_le_bytes());
+        let schedule = crate::utils::BonfidaVestingSchedule::try_from_bytes(&data).unwrap();
+
+        let mut policy = PolicyConfig::from_legacy(legacy_policy());
+        policy.vesting_mint = Pubkey::new_from_array([2u8; 32]);
+        assert!(policy.verify_vesting_source(&schedule).is_ok());
+
+        // A sender allow-list cannot be checked against a schedule without a sender
+        policy.allowed_senders = vec![Pubkey::new_unique()];
+        assert!(policy.verify_vesting_source(&schedule).is_err());
+    }
+
+    #[test]
+    fn test_init_space_fits_largest_vesting_filter() {
+        let mut policy = PolicyConfig::from_legacy(legacy_policy());
+        policy.allowed_senders = vec![Pubkey::new_unique(); MAX_ALLOWED_SENDERS];
+        policy.vesting_programs = vec![
+            VestingProgram {
+                kind: VestingProgramKind::Streamflow,
+                program_id: Pubkey::new_unique(),
+            };
+            MAX_VESTING_PROGRAMS
+        ];
+
+        let bytes = policy.try_to_vec().unwrap();
+        assert_eq!(bytes.len(), PolicyConfig::INIT_SPACE);
+    }
 }
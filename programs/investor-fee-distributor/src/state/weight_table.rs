@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::ErrorCode};
+
+/// Static weight of a single investor
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WeightEntry {
+    /// Investor wallet (owner of the payout ATA)
+    pub investor: Pubkey,
+    /// Pro-rata weight used in place of a locked amount
+    pub weight: u64,
+}
+
+impl WeightEntry {
+    pub const SIZE: usize =
+        32 +    // investor
+        8;      // weight
+}
+
+/// Authority-published investor weights, used in static table weight mode
+#[account]
+pub struct WeightTable {
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+    /// Vault this table serves
+    pub vault: Pubkey,
+    /// Sum of all weights
+    pub total_weight: u64,
+    /// Investor weights
+    pub entries: Vec<WeightEntry>,
+}
+
+impl WeightTable {
+    pub const INIT_SPACE: usize =
+        1 +     // bump
+        32 +    // vault
+        8 +     // total_weight
+        4 + MAX_WEIGHT_ENTRIES * WeightEntry::SIZE; // entries
+
+    /// Weight of an investor
+    pub fn weight_of(&self, investor: &Pubkey) -> Result<u64> {
+        self.entries
+            .iter()
+            .find(|entry| entry.investor == *investor)
+            .map(|entry| entry.weight)
+            .ok_or_else(|| ErrorCode::InvestorNotInWeightTable.into())
+    }
+
+    /// Add a new investor
+    pub fn add(&mut self, investor: Pubkey, weight: u64) -> Result<()> {
+        require!(
+            self.entries.iter().all(|entry| entry.investor != investor),
+            ErrorCode::InvestorAlreadyInWeightTable
+        );
+        require!(
+            self.entries.len() < MAX_WEIGHT_ENTRIES,
+            ErrorCode::WeightTableFull
+        );
+
+        self.total_weight = self.total_weight
+            .checked_add(weight)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        self.entries.push(WeightEntry { investor, weight });
+        Ok(())
+    }
+
+    /// Change an existing investor's weight, returning the old weight
+    pub fn update(&mut self, investor: &Pubkey, weight: u64) -> Result<u64> {
+        let entry = self.entries
+            .iter_mut()
+            .find(|entry| entry.investor == *investor)
+            .ok_or(ErrorCode::InvestorNotInWeightTable)?;
+        let old_weight = entry.weight;
+        entry.weight = weight;
+
+        self.total_weight = self.total_weight
+            .checked_sub(old_weight)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?
+            .checked_add(weight)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(old_weight)
+    }
+
+    /// Remove an investor, returning the removed weight
+    pub fn remove(&mut self, investor: &Pubkey) -> Result<u64> {
+        let index = self.entries
+            .iter()
+            .position(|entry| entry.investor == *investor)
+            .ok_or(ErrorCode::InvestorNotInWeightTable)?;
+        let old_weight = self.entries.swap_remove(index).weight;
+
+        self.total_weight = self.total_weight
+            .checked_sub(old_weight)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+        Ok(old_weight)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_update_remove_keep_total() {
+        let mut table = WeightTable {
+            bump: 0,
+            vault: Pubkey::default(),
+            total_weight: 0,
+            entries: vec![],
+        };
+        let alice = Pubkey::new_unique();
+        let bob = Pubkey::new_unique();
+
+        table.add(alice, 300).unwrap();
+        table.add(bob, 700).unwrap();
+        assert_eq!(table.total_weight, 1000);
+        assert!(table.add(alice, 1).is_err());
+
+        assert_eq!(table.update(&alice, 500).unwrap(), 300);
+        assert_eq!(table.weight_of(&alice).unwrap(), 500);
+        assert_eq!(table.total_weight, 1200);
+
+        assert_eq!(table.remove(&bob).unwrap(), 700);
+        assert_eq!(table.total_weight, 500);
+        assert!(table.weight_of(&bob).is_err());
+        assert!(table.remove(&bob).is_err());
+    }
+}
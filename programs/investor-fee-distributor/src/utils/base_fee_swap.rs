@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use crate::{constants::SWAP_PRICE_SCALE, error::ErrorCode, utils::apply_bps, dynamic_amm, dynamic_vault};
+
+/// Token reserves a DAMM v1 pool holds through its two dynamic vaults
+pub struct DynamicAmmReserves {
+    /// Token A (base) reserve
+    pub reserve_a: u64,
+    /// Token B (quote) reserve
+    pub reserve_b: u64,
+    /// Protocol fee account for token A (receives part of a base-in swap fee)
+    pub protocol_token_a_fee: Pubkey,
+}
+
+/// Read a constant-product DAMM v1 pool's reserves from its vault accounts
+pub fn read_dynamic_amm_reserves<'info>(
+    pool: &AccountInfo<'info>,
+    a_vault: &AccountInfo<'info>,
+    b_vault: &AccountInfo<'info>,
+    a_vault_lp: &AccountInfo<'info>,
+    b_vault_lp: &AccountInfo<'info>,
+    a_vault_lp_mint: &AccountInfo<'info>,
+    b_vault_lp_mint: &AccountInfo<'info>,
+) -> Result<DynamicAmmReserves> {
+    // SECURITY: Only accounts owned by the AMM and vault programs are decoded
+    require_keys_eq!(*pool.owner, dynamic_amm::ID, ErrorCode::InvalidPool);
+    require_keys_eq!(*a_vault.owner, dynamic_vault::ID, ErrorCode::InvalidPool);
+    require_keys_eq!(*b_vault.owner, dynamic_vault::ID, ErrorCode::InvalidPool);
+
+    let pool_state = Box::new(dynamic_amm::accounts::Pool::try_deserialize(
+        &mut &pool.try_borrow_data()?[..],
+    )?);
+    require!(
+        matches!(pool_state.curve_type, dynamic_amm::types::CurveType::ConstantProduct),
+        ErrorCode::UnsupportedSwapCurve
+    );
+    require!(
+        pool_state.a_vault == a_vault.key()
+            && pool_state.b_vault == b_vault.key()
+            && pool_state.a_vault_lp == a_vault_lp.key()
+            && pool_state.b_vault_lp == b_vault_lp.key(),
+        ErrorCode::InvalidPool
+    );
+
+    let reserve_a = pool_vault_reserve(a_vault, a_vault_lp, a_vault_lp_mint)?;
+    let reserve_b = pool_vault_reserve(b_vault, b_vault_lp, b_vault_lp_mint)?;
+
+    Ok(DynamicAmmReserves {
+        reserve_a,
+        reserve_b,
+        protocol_token_a_fee: pool_state.protocol_token_a_fee,
+    })
+}
+
+/// Pool's share of one dynamic vault: its vault LP balance over the LP supply
+fn pool_vault_reserve(
+    vault: &AccountInfo,
+    vault_lp: &AccountInfo,
+    vault_lp_mint: &AccountInfo,
+) -> Result<u64> {
+    let vault_state = Box::new(dynamic_vault::accounts::Vault::try_deserialize(
+        &mut &vault.try_borrow_data()?[..],
+    )?);
+    require_keys_eq!(vault_state.lp_mint, vault_lp_mint.key(), ErrorCode::InvalidPool);
+
+    let lp_balance = TokenAccount::try_deserialize(&mut &vault_lp.try_borrow_data()?[..])?.amount;
+    let lp_supply = Mint::try_deserialize(&mut &vault_lp_mint.try_borrow_data()?[..])?.supply;
+
+    vault_share(vault_state.total_amount, lp_balance, lp_supply)
+}
+
+/// Amount of a vault's liquidity backing `lp_balance` of its `lp_supply`
+pub fn vault_share(vault_total_amount: u64, lp_balance: u64, lp_supply: u64) -> Result<u64> {
+    if lp_supply == 0 {
+        return Ok(0);
+    }
+
+    let share = (vault_total_amount as u128)
+        .checked_mul(lp_balance as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / lp_supply as u128;
+
+    u64::try_from(share).map_err(|_| ErrorCode::ArithmeticOverflow.into())
+}
+
+/// Minimum output accepted for a swap: the spot-price output less the allowed
+/// slippage (which must cover the pool's trade fee and price impact), but never
+/// less than the authority's price floor
+/// Reserves read in the same transaction can be skewed by a sandwiching trade;
+/// the floor is what bounds the swap in that case
+pub fn min_swap_out(
+    amount_in: u64,
+    reserve_in: u64,
+    reserve_out: u64,
+    max_slippage_bps: u16,
+    min_swap_price: u64,
+) -> Result<u64> {
+    require!(reserve_in > 0 && reserve_out > 0, ErrorCode::InvalidPool);
+
+    let spot_out = (amount_in as u128)
+        .checked_mul(reserve_out as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / reserve_in as u128;
+    let spot_out = u64::try_from(spot_out).map_err(|_| ErrorCode::ArithmeticOverflow)?;
+
+    let slippage_out = apply_bps(spot_out, 10000u16.saturating_sub(max_slippage_bps))?;
+    Ok(slippage_out.max(price_floor_out(amount_in, min_swap_price)?))
+}
+
+/// Output owed for `amount_in` at a floor of `min_swap_price` quote atoms per
+/// SWAP_PRICE_SCALE base atoms (rounded up)
+pub fn price_floor_out(amount_in: u64, min_swap_price: u64) -> Result<u64> {
+    let floor_out = (amount_in as u128)
+        .checked_mul(min_swap_price as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        .div_ceil(SWAP_PRICE_SCALE as u128);
+
+    u64::try_from(floor_out).map_err(|_| ErrorCode::ArithmeticOverflow.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vault_share() {
+        // Pool holds a quarter of the vault LP
+        assert_eq!(vault_share(1_000_000, 250, 1_000).unwrap(), 250_000);
+
+        // Empty vault LP supply
+        assert_eq!(vault_share(1_000_000, 0, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_min_swap_out() {
+        // 1:2 price, 1% slippage: spot 2_000, minimum 1_980
+        assert_eq!(min_swap_out(1_000, 500_000, 1_000_000, 100, 0).unwrap(), 1_980);
+
+        // No slippage allowed
+        assert_eq!(min_swap_out(1_000, 500_000, 1_000_000, 0, 0).unwrap(), 2_000);
+
+        // Empty reserves cannot be priced
+        assert!(min_swap_out(1_000, 0, 1_000_000, 100, 0).is_err());
+    }
+
+    #[test]
+    fn test_price_floor_bounds_manipulated_reserves() {
+        // Floor of 1.9 quote per base
+        let floor = 1_900_000_000;
+        assert_eq!(price_floor_out(1_000, floor).unwrap(), 1_900);
+        assert_eq!(price_floor_out(1, 1).unwrap(), 1);
+
+        // Honest 1:2 reserves: the slippage bound is the tighter one
+        assert_eq!(min_swap_out(1_000, 500_000, 1_000_000, 100, floor).unwrap(), 1_980);
+
+        // A front-run dumps base into the pool, halving the spot price:
+        // without the floor the crank would accept ~990, with it at least 1_900
+        assert_eq!(min_swap_out(1_000, 1_000_000, 1_000_000, 100, 0).unwrap(), 990);
+        assert_eq!(min_swap_out(1_000, 1_000_000, 1_000_000, 100, floor).unwrap(), 1_900);
+    }
+}
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::error::ErrorCode;
+use super::VestingSource;
+
+/// Bonfida token-vesting account: header followed by release schedules
+/// Full layout available at: https://github.com/Bonfida/token-vesting
+#[derive(Clone, Debug)]
+pub struct BonfidaVestingSchedule {
+    /// Token account receiving released tokens
+    pub destination_address: Pubkey,
+    /// Mint being vested
+    pub mint_address: Pubkey,
+    /// Releases as (release_time, amount); amounts are zeroed once released
+    pub schedules: Vec<(u64, u64)>,
+}
+
+impl BonfidaVestingSchedule {
+    /// Header: destination (32) + mint (32) + is_initialized (1)
+    pub const HEADER_LEN: usize = 65;
+    /// Schedule: release_time (8) + amount (8)
+    pub const SCHEDULE_LEN: usize = 16;
+
+    /// Parse the header and schedules from raw account data
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() >= Self::HEADER_LEN
+                && (data.len() - Self::HEADER_LEN).is_multiple_of(Self::SCHEDULE_LEN)
+                && data[64] == 1,
+            ErrorCode::VestingAccountMismatch
+        );
+
+        let read_u64 = |bytes: &[u8]| -> u64 {
+            let mut value = [0u8; 8];
+            value.copy_from_slice(bytes);
+            u64::from_le_bytes(value)
+        };
+
+        let mut destination = [0u8; 32];
+        destination.copy_from_slice(&data[0..32]);
+        let mut mint = [0u8; 32];
+        mint.copy_from_slice(&data[32..64]);
+
+        let schedules = data[Self::HEADER_LEN..]
+            .chunks_exact(Self::SCHEDULE_LEN)
+            .map(|schedule| (read_u64(&schedule[..8]), read_u64(&schedule[8..])))
+            .collect();
+
+        Ok(Self {
+            destination_address: Pubkey::new_from_array(destination),
+            mint_address: Pubkey::new_from_array(mint),
+            schedules,
+        })
+    }
+}
+
+impl VestingSource for BonfidaVestingSchedule {
+    fn mint(&self) -> Pubkey {
+        self.mint_address
+    }
+
+    /// Bonfida accounts do not record who funded them
+    fn sender(&self) -> Option<Pubkey> {
+        None
+    }
+
+    /// Schedules only record a destination token account
+    fn recipient(&self) -> Option<Pubkey> {
+        None
+    }
+
+    /// Payouts go to the schedule's destination token account itself
+    fn is_payout_account(&self, key: &Pubkey, _token_account: &TokenAccount) -> bool {
+        *key == self.destination_address
+    }
+
+    fn locked_at(&self, timestamp: i64) -> Result<u64> {
+        let timestamp = timestamp.max(0) as u64;
+
+        self.schedules
+            .iter()
+            .filter(|(release_time, _)| *release_time > timestamp)
+            .try_fold(0u64, |locked, (_, amount)| locked.checked_add(*amount))
+            .ok_or_else(|| ErrorCode::ArithmeticOverflow.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locked_sums_future_releases() {
+        let mut data = vec![0u8; 65];
+        data[0..32].copy_from_slice(&[1u8; 32]);
+        data[32..64].copy_from_slice(&[2u8; 32]);
+        data[64] = 1;
+        for (release_time, amount) in [(1000u64, 100u64), (2000, 200), (3000, 300)] {
+            data.extend_from_slice(&release_time.to_le_bytes());
+            data.extend_from_slice(&amount.to_le_bytes());
+        }
+
+        let schedule = BonfidaVestingSchedule::try_from_bytes(&data).unwrap();
+        assert_eq!(schedule.mint(), Pubkey::new_from_array([2u8; 32]));
+        assert_eq!(schedule.sender(), None);
+
+        assert_eq!(schedule.locked_at(500).unwrap(), 600);
+        assert_eq!(schedule.locked_at(1000).unwrap(), 500);
+        assert_eq!(schedule.locked_at(2500).unwrap(), 300);
+        assert_eq!(schedule.locked_at(3000).unwrap(), 0);
+
+        // Uninitialized or misaligned accounts are rejected
+        data[64] = 0;
+        assert!(BonfidaVestingSchedule::try_from_bytes(&data).is_err());
+        data[64] = 1;
+        data.push(0);
+        assert!(BonfidaVestingSchedule::try_from_bytes(&data).is_err());
+    }
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use crate::{error::ErrorCode, state::CollectFeeMode, cp_amm};
+
+/// Fields of the cp-amm (DAMM v2) Pool account needed for validation
+/// Full layout available at: https://github.com/MeteoraAg/damm-v2
+#[derive(Clone, Debug)]
+pub struct CpAmmPool {
+    /// Token A mint
+    pub token_a_mint: Pubkey,
+    /// Token B mint
+    pub token_b_mint: Pubkey,
+    /// Raw collect fee mode (0 = both tokens, 1 = token B only)
+    pub collect_fee_mode: u8,
+}
+
+impl CpAmmPool {
+    /// Anchor discriminator of the cp-amm Pool account
+    pub const DISCRIMINATOR: [u8; 8] = [241, 154, 109, 4, 17, 177, 109, 188];
+
+    /// Byte offsets (including the 8-byte discriminator)
+    /// pool_fees (160 bytes) precedes the mints
+    pub const TOKEN_A_MINT_OFFSET: usize = 168;
+    pub const TOKEN_B_MINT_OFFSET: usize = 200;
+    pub const COLLECT_FEE_MODE_OFFSET: usize = 484;
+
+    /// Parse the validated fields from raw account data
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() > Self::COLLECT_FEE_MODE_OFFSET
+                && data[..8] == Self::DISCRIMINATOR,
+            ErrorCode::InvalidPool
+        );
+
+        let read_pubkey = |offset: usize| -> Pubkey {
+            let mut bytes = [0u8; 32];
+            bytes.copy_from_slice(&data[offset..offset + 32]);
+            Pubkey::new_from_array(bytes)
+        };
+
+        Ok(Self {
+            token_a_mint: read_pubkey(Self::TOKEN_A_MINT_OFFSET),
+            token_b_mint: read_pubkey(Self::TOKEN_B_MINT_OFFSET),
+            collect_fee_mode: data[Self::COLLECT_FEE_MODE_OFFSET],
+        })
+    }
+
+    /// Fee collection mode of this pool
+    pub fn fee_mode(&self) -> Result<CollectFeeMode> {
+        CollectFeeMode::try_from_u8(self.collect_fee_mode)
+    }
+}
+
+/// Parse a cp-amm pool account from account info
+pub fn parse_cp_amm_pool(account_info: &AccountInfo) -> Result<CpAmmPool> {
+    // SECURITY: Validate account owner to prevent fake pool accounts
+    require!(
+        account_info.owner == &cp_amm::ID,
+        ErrorCode::InvalidPool
+    );
+
+    let data = account_info.try_borrow_data()?;
+    CpAmmPool::try_from_bytes(&data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_pool_data(collect_fee_mode: u8) -> Vec<u8> {
+        let mut data = vec![0u8; 1120];
+        data[..8].copy_from_slice(&CpAmmPool::DISCRIMINATOR);
+        data[CpAmmPool::TOKEN_A_MINT_OFFSET..CpAmmPool::TOKEN_A_MINT_OFFSET + 32]
+            .copy_from_slice(&[1u8; 32]);
+        data[CpAmmPool::TOKEN_B_MINT_OFFSET..CpAmmPool::TOKEN_B_MINT_OFFSET + 32]
+            .copy_from_slice(&[2u8; 32]);
+        data[CpAmmPool::COLLECT_FEE_MODE_OFFSET] = collect_fee_mode;
+        data
+    }
+
+    #[test]
+    fn test_parse_quote_only_pool() {
+        let pool = CpAmmPool::try_from_bytes(&create_test_pool_data(1)).unwrap();
+
+        assert_eq!(pool.token_a_mint, Pubkey::new_from_array([1u8; 32]));
+        assert_eq!(pool.token_b_mint, Pubkey::new_from_array([2u8; 32]));
+        assert_eq!(pool.fee_mode().unwrap(), CollectFeeMode::OnlyB);
+    }
+
+    #[test]
+    fn test_parse_both_token_pool() {
+        let pool = CpAmmPool::try_from_bytes(&create_test_pool_data(0)).unwrap();
+        assert_eq!(pool.fee_mode().unwrap(), CollectFeeMode::BothToken);
+    }
+
+    #[test]
+    fn test_unknown_fee_mode_rejected() {
+        let pool = CpAmmPool::try_from_bytes(&create_test_pool_data(7)).unwrap();
+        assert!(pool.fee_mode().is_err());
+    }
+
+    #[test]
+    fn test_wrong_discriminator_rejected() {
+        let mut data = create_test_pool_data(1);
+        data[0] ^= 0xff;
+        assert!(CpAmmPool::try_from_bytes(&data).is_err());
+
+        // Truncated account
+        assert!(CpAmmPool::try_from_bytes(&data[..100]).is_err());
+    }
+}
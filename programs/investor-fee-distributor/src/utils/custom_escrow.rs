@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::error::ErrorCode;
+use super::VestingSource;
+
+/// In-house escrow holding a fixed amount until a single unlock time
+/// Layout: discriminator (8) + recipient (32) + mint (32) + sender (32)
+///         + amount (u64) + unlock_ts (i64)
+#[derive(Clone, Debug)]
+pub struct CustomEscrow {
+    /// Wallet receiving the tokens at unlock
+    pub recipient: Pubkey,
+    /// Mint held in escrow
+    pub mint: Pubkey,
+    /// Wallet that funded the escrow
+    pub sender: Pubkey,
+    /// Amount held until unlock
+    pub amount: u64,
+    /// Timestamp when the whole amount unlocks
+    pub unlock_ts: i64,
+}
+
+impl CustomEscrow {
+    /// Anchor account discriminator: sha256("account:CustomEscrow")[..8]
+    pub const DISCRIMINATOR: [u8; 8] = [49, 130, 42, 13, 64, 16, 112, 244];
+
+    pub const RECIPIENT_OFFSET: usize = 8;
+    pub const MINT_OFFSET: usize = 40;
+    pub const SENDER_OFFSET: usize = 72;
+    pub const AMOUNT_OFFSET: usize = 104;
+    pub const UNLOCK_TS_OFFSET: usize = 112;
+    pub const LEN: usize = Self::UNLOCK_TS_OFFSET + 8;
+
+    /// Parse the escrow from raw account data
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() >= Self::LEN && data[..8] == Self::DISCRIMINATOR,
+            ErrorCode::VestingAccountMismatch
+        );
+
+        let read_pubkey = |offset: usize| -> Pubkey {
+            let mut bytes = [0u8; 32];
+            bytes.copy_from_slice(&data[offset..offset + 32]);
+            Pubkey::new_from_array(bytes)
+        };
+        let mut amount = [0u8; 8];
+        amount.copy_from_slice(&data[Self::AMOUNT_OFFSET..Self::AMOUNT_OFFSET + 8]);
+        let mut unlock_ts = [0u8; 8];
+        unlock_ts.copy_from_slice(&data[Self::UNLOCK_TS_OFFSET..Self::UNLOCK_TS_OFFSET + 8]);
+
+        Ok(Self {
+            recipient: read_pubkey(Self::RECIPIENT_OFFSET),
+            mint: read_pubkey(Self::MINT_OFFSET),
+            sender: read_pubkey(Self::SENDER_OFFSET),
+            amount: u64::from_le_bytes(amount),
+            unlock_ts: i64::from_le_bytes(unlock_ts),
+        })
+    }
+}
+
+impl VestingSource for CustomEscrow {
+    fn mint(&self) -> Pubkey {
+        self.mint
+    }
+
+    fn sender(&self) -> Option<Pubkey> {
+        Some(self.sender)
+    }
+
+    fn recipient(&self) -> Option<Pubkey> {
+        Some(self.recipient)
+    }
+
+    fn is_payout_account(&self, _key: &Pubkey, token_account: &TokenAccount) -> bool {
+        token_account.owner == self.recipient
+    }
+
+    fn locked_at(&self, timestamp: i64) -> Result<u64> {
+        Ok(if timestamp < self.unlock_ts { self.amount } else { 0 })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_unlock() {
+        let mut data = vec![0u8; CustomEscrow::LEN];
+        data[..8].copy_from_slice(&CustomEscrow::DISCRIMINATOR);
+        data[40..72].copy_from_slice(&[2u8; 32]);
+        data[104..112].copy_from_slice(&500u64.to_le_bytes());
+        data[112..120].copy_from_slice(&1000i64.to_le_bytes());
+
+        let escrow = CustomEscrow::try_from_bytes(&data).unwrap();
+        assert_eq!(escrow.mint(), Pubkey::new_from_array([2u8; 32]));
+        assert_eq!(escrow.locked_at(999).unwrap(), 500);
+        assert_eq!(escrow.locked_at(1000).unwrap(), 0);
+
+        assert!(CustomEscrow::try_from_bytes(&data[..100]).is_err());
+    }
+
+    #[test]
+    fn test_wrong_discriminator_rejected() {
+        let mut data = vec![0u8; CustomEscrow::LEN];
+        data[..8].copy_from_slice(&[1u8; 8]);
+
+        assert!(CustomEscrow::try_from_bytes(&data).is_err());
+    }
+}
@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::error::ErrorCode;
+use super::VestingSource;
+
+/// Fields of a Jupiter Lock `VestingEscrow` account needed for locked amounts
+/// Full layout available at: https://github.com/jup-ag/jup-lock
+#[derive(Clone, Debug)]
+pub struct JupiterVestingEscrow {
+    /// Wallet receiving the vested tokens
+    pub recipient: Pubkey,
+    /// Mint being vested
+    pub token_mint: Pubkey,
+    /// Wallet that created and funded the escrow
+    pub creator: Pubkey,
+    /// Timestamp of the cliff (linear release starts here)
+    pub cliff_time: u64,
+    /// Seconds per release period
+    pub frequency: u64,
+    /// Amount released at the cliff
+    pub cliff_unlock_amount: u64,
+    /// Amount released per period
+    pub amount_per_period: u64,
+    /// Number of release periods
+    pub number_of_period: u64,
+    /// Timestamp when the escrow was cancelled (0 if not cancelled)
+    pub cancelled_at: u64,
+}
+
+impl JupiterVestingEscrow {
+    /// Anchor discriminator of the VestingEscrow account
+    pub const DISCRIMINATOR: [u8; 8] = [244, 119, 183, 4, 73, 116, 135, 195];
+
+    /// Byte offsets (including the 8-byte discriminator)
+    pub const RECIPIENT_OFFSET: usize = 8;
+    pub const TOKEN_MINT_OFFSET: usize = 40;
+    pub const CREATOR_OFFSET: usize = 72;
+    // 104..144: base, bumps, modes and padding
+    pub const CLIFF_TIME_OFFSET: usize = 144;
+    pub const FREQUENCY_OFFSET: usize = 152;
+    pub const CLIFF_UNLOCK_AMOUNT_OFFSET: usize = 160;
+    pub const AMOUNT_PER_PERIOD_OFFSET: usize = 168;
+    pub const NUMBER_OF_PERIOD_OFFSET: usize = 176;
+    // 184..200: total_claimed_amount, vesting_start_time
+    pub const CANCELLED_AT_OFFSET: usize = 200;
+    pub const MIN_LEN: usize = Self::CANCELLED_AT_OFFSET + 8;
+
+    /// Parse the escrow from raw account data
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() >= Self::MIN_LEN && data[..8] == Self::DISCRIMINATOR,
+            ErrorCode::VestingAccountMismatch
+        );
+
+        let read_u64 = |offset: usize| -> u64 {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&data[offset..offset + 8]);
+            u64::from_le_bytes(bytes)
+        };
+        let read_pubkey = |offset: usize| -> Pubkey {
+            let mut bytes = [0u8; 32];
+            bytes.copy_from_slice(&data[offset..offset + 32]);
+            Pubkey::new_from_array(bytes)
+        };
+
+        Ok(Self {
+            recipient: read_pubkey(Self::RECIPIENT_OFFSET),
+            token_mint: read_pubkey(Self::TOKEN_MINT_OFFSET),
+            creator: read_pubkey(Self::CREATOR_OFFSET),
+            cliff_time: read_u64(Self::CLIFF_TIME_OFFSET),
+            frequency: read_u64(Self::FREQUENCY_OFFSET),
+            cliff_unlock_amount: read_u64(Self::CLIFF_UNLOCK_AMOUNT_OFFSET),
+            amount_per_period: read_u64(Self::AMOUNT_PER_PERIOD_OFFSET),
+            number_of_period: read_u64(Self::NUMBER_OF_PERIOD_OFFSET),
+            cancelled_at: read_u64(Self::CANCELLED_AT_OFFSET),
+        })
+    }
+
+    /// Total amount the escrow vests
+    pub fn total_amount(&self) -> Result<u64> {
+        self.amount_per_period
+            .checked_mul(self.number_of_period)
+            .and_then(|linear| linear.checked_add(self.cliff_unlock_amount))
+            .ok_or_else(|| ErrorCode::ArithmeticOverflow.into())
+    }
+}
+
+impl VestingSource for JupiterVestingEscrow {
+    fn mint(&self) -> Pubkey {
+        self.token_mint
+    }
+
+    fn sender(&self) -> Option<Pubkey> {
+        Some(self.creator)
+    }
+
+    fn recipient(&self) -> Option<Pubkey> {
+        Some(self.recipient)
+    }
+
+    fn is_payout_account(&self, _key: &Pubkey, token_account: &TokenAccount) -> bool {
+        token_account.owner == self.recipient
+    }
+
+    fn locked_at(&self, timestamp: i64) -> Result<u64> {
+        let timestamp = timestamp.max(0) as u64;
+
+        // Cancellation releases everything still locked
+        if self.cancelled_at > 0 && timestamp >= self.cancelled_at {
+            return Ok(0);
+        }
+
+        let total = self.total_amount()?;
+        if timestamp < self.cliff_time {
+            return Ok(total);
+        }
+
+        let periods_elapsed = (timestamp - self.cliff_time)
+            .checked_div(self.frequency)
+            .unwrap_or(0)
+            .min(self.number_of_period);
+
+        let unlocked = periods_elapsed
+            .checked_mul(self.amount_per_period)
+            .and_then(|linear| linear.checked_add(self.cliff_unlock_amount))
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        Ok(total.saturating_sub(unlocked))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_escrow_data() -> Vec<u8> {
+        let mut data = vec![0u8; 288];
+        data[..8].copy_from_slice(&JupiterVestingEscrow::DISCRIMINATOR);
+        data[8..40].copy_from_slice(&[1u8; 32]);    // recipient
+        data[40..72].copy_from_slice(&[2u8; 32]);   // token_mint
+        data[72..104].copy_from_slice(&[3u8; 32]);  // creator
+        data[144..152].copy_from_slice(&1000u64.to_le_bytes()); // cliff_time
+        data[152..160].copy_from_slice(&100u64.to_le_bytes());  // frequency
+        data[160..168].copy_from_slice(&200u64.to_le_bytes());  // cliff_unlock_amount
+        data[168..176].copy_from_slice(&80u64.to_le_bytes());   // amount_per_period
+        data[176..184].copy_from_slice(&10u64.to_le_bytes());   // number_of_period
+        data
+    }
+
+    #[test]
+    fn test_parse_and_unlock_schedule() {
+        let escrow = JupiterVestingEscrow::try_from_bytes(&create_test_escrow_data()).unwrap();
+        assert_eq!(escrow.recipient, Pubkey::new_from_array([1u8; 32]));
+        assert_eq!(escrow.mint(), Pubkey::new_from_array([2u8; 32]));
+        assert_eq!(escrow.sender(), Some(Pubkey::new_from_array([3u8; 32])));
+        assert_eq!(escrow.total_amount().unwrap(), 1000);
+
+        // Before cliff: everything locked
+        assert_eq!(escrow.locked_at(999).unwrap(), 1000);
+        // At cliff: cliff amount released
+        assert_eq!(escrow.locked_at(1000).unwrap(), 800);
+        // 3 periods after cliff
+        assert_eq!(escrow.locked_at(1350).unwrap(), 560);
+        // After the last period
+        assert_eq!(escrow.locked_at(5000).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_cancelled_escrow() {
+        let mut data = create_test_escrow_data();
+        data[200..208].copy_from_slice(&1200u64.to_le_bytes());
+        let escrow = JupiterVestingEscrow::try_from_bytes(&data).unwrap();
+
+        assert_eq!(escrow.locked_at(1100).unwrap(), 720);
+        assert_eq!(escrow.locked_at(1200).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_wrong_discriminator_rejected() {
+        let mut data = create_test_escrow_data();
+        data[0] ^= 0xff;
+        assert!(JupiterVestingEscrow::try_from_bytes(&data).is_err());
+    }
+}
@@ -49,6 +49,30 @@ pub fn calculate_f_locked_bps(locked_total: u64, y0: u64) -> Result<u64> {
     u64::try_from(result).map_err(|_| ErrorCode::ArithmeticOverflow.into())
 }
 
+/// Calculate the investor share of a day's fees
+/// Returns: min(floor(total * min(f_locked_bps, share_bps) / 10000), daily_cap)
+pub fn calculate_investor_fee_quote(
+    total_available: u64,
+    locked_total: u64,
+    y0: u64,
+    investor_fee_share_bps: u16,
+    daily_cap: Option<u64>,
+) -> Result<u64> {
+    if locked_total == 0 {
+        return Ok(0);
+    }
+
+    let f_locked_bps = calculate_f_locked_bps(locked_total, y0)?;
+    let eligible_investor_share_bps = f_locked_bps.min(investor_fee_share_bps as u64);
+
+    let investor_fee_quote = apply_bps(total_available, eligible_investor_share_bps as u16)?;
+
+    Ok(match daily_cap {
+        Some(cap) => investor_fee_quote.min(cap),
+        None => investor_fee_quote,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -97,4 +121,19 @@ mod tests {
         // Half locked = 5000 bps (50%)
         assert_eq!(calculate_f_locked_bps(500, 1000).unwrap(), 5000);
     }
+
+    #[test]
+    fn test_investor_fee_quote() {
+        // 50% locked, 70% max share: investors get 50% of 1000
+        assert_eq!(calculate_investor_fee_quote(1000, 500, 1000, 7000, None).unwrap(), 500);
+
+        // 90% locked, 70% max share: capped by policy share
+        assert_eq!(calculate_investor_fee_quote(1000, 900, 1000, 7000, None).unwrap(), 700);
+
+        // Daily cap clips the investor share
+        assert_eq!(calculate_investor_fee_quote(1000, 900, 1000, 7000, Some(300)).unwrap(), 300);
+
+        // Nothing locked: investors get nothing
+        assert_eq!(calculate_investor_fee_quote(1000, 0, 1000, 7000, None).unwrap(), 0);
+    }
 }
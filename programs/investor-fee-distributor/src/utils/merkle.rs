@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+/// Domain separators so a leaf can never be passed off as an inner node
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// One proof step: the sibling's hash and the locked total beneath it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MerkleProofNode {
+    pub hash: [u8; 32],
+    pub locked: u64,
+}
+
+/// Hash of one (index, investor, amount, locked) distribution leaf
+pub fn merkle_leaf(index: u32, investor: &Pubkey, amount: u64, locked: u64) -> [u8; 32] {
+    hashv(&[
+        LEAF_PREFIX,
+        &index.to_le_bytes(),
+        investor.as_ref(),
+        &amount.to_le_bytes(),
+        &locked.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Hash of two sibling nodes committing to the locked total beneath both
+/// (sorted, so proofs need no left/right flags)
+pub fn merkle_node(a: &[u8; 32], b: &[u8; 32], locked: u64) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, left, right, &locked.to_le_bytes()]).to_bytes()
+}
+
+/// Check that a leaf is included under a root whose nodes add up to `locked_total`
+pub fn verify_merkle_proof(
+    proof: &[MerkleProofNode],
+    root: &[u8; 32],
+    locked_total: u64,
+    leaf: [u8; 32],
+    leaf_locked: u64,
+) -> bool {
+    let computed = proof.iter().try_fold((leaf, leaf_locked), |(node, locked), sibling| {
+        let locked = locked.checked_add(sibling.locked)?;
+        Some((merkle_node(&node, &sibling.hash, locked), locked))
+    });
+    computed == Some((*root, locked_total))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_proof() {
+        let investors: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let locked = [1_000, 2_000, 3_000, 4_000];
+        let leaves: Vec<[u8; 32]> = investors
+            .iter()
+            .enumerate()
+            .map(|(i, investor)| merkle_leaf(i as u32, investor, 100 * (i as u64 + 1), locked[i]))
+            .collect();
+
+        let n01 = merkle_node(&leaves[0], &leaves[1], 3_000);
+        let n23 = merkle_node(&leaves[2], &leaves[3], 7_000);
+        let root = merkle_node(&n01, &n23, 10_000);
+
+        // Leaf 2 is proven by its sibling and the other subtree
+        let proof = [
+            MerkleProofNode { hash: leaves[3], locked: 4_000 },
+            MerkleProofNode { hash: n01, locked: 3_000 },
+        ];
+        assert!(verify_merkle_proof(&proof, &root, 10_000, leaves[2], 3_000));
+
+        // Wrong amount or wrong index does not verify
+        let forged = merkle_leaf(2, &investors[2], 999, 3_000);
+        assert!(!verify_merkle_proof(&proof, &root, 10_000, forged, 3_000));
+        let moved = merkle_leaf(3, &investors[2], 300, 3_000);
+        assert!(!verify_merkle_proof(&proof, &root, 10_000, moved, 3_000));
+
+        // An inner node cannot be claimed as a leaf
+        let inner = [MerkleProofNode { hash: n23, locked: 7_000 }];
+        assert!(!verify_merkle_proof(&inner, &root, 10_000, merkle_leaf(0, &investors[0], 0, 3_000), 3_000));
+    }
+
+    #[test]
+    fn test_root_commits_locked_total() {
+        let investors: Vec<Pubkey> = (0..2).map(|_| Pubkey::new_unique()).collect();
+        let leaf_0 = merkle_leaf(0, &investors[0], 100, 1_000);
+        let leaf_1 = merkle_leaf(1, &investors[1], 300, 3_000);
+        let root = merkle_node(&leaf_0, &leaf_1, 4_000);
+        let proof = [MerkleProofNode { hash: leaf_1, locked: 3_000 }];
+
+        assert!(verify_merkle_proof(&proof, &root, 4_000, leaf_0, 1_000));
+
+        // A root cannot be posted with a different locked total than it commits to
+        assert!(!verify_merkle_proof(&proof, &root, 5_000, leaf_0, 1_000));
+
+        // Nor can a claimant restate its own or its sibling's locked amount
+        assert!(!verify_merkle_proof(&proof, &root, 4_000, leaf_0, 2_000));
+        let inflated = [MerkleProofNode { hash: leaf_1, locked: 2_000 }];
+        assert!(!verify_merkle_proof(&inflated, &root, 3_000, leaf_0, 1_000));
+    }
+}
@@ -1,5 +1,23 @@
+pub mod base_fee_swap;
+pub mod bonfida_vesting;
+pub mod cp_amm;
+pub mod custom_escrow;
+pub mod jupiter_lock;
 pub mod math;
+pub mod merkle;
 pub mod streamflow;
+pub mod transfer_fee;
+pub mod vesting;
+pub mod weights;
 
+pub use base_fee_swap::*;
+pub use bonfida_vesting::*;
+pub use cp_amm::*;
+pub use custom_escrow::*;
+pub use jupiter_lock::*;
 pub use math::*;
+pub use merkle::*;
 pub use streamflow::*;
+pub use transfer_fee::*;
+pub use vesting::*;
+pub use weights::*;
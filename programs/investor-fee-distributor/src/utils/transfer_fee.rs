@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::{
+    self,
+    spl_token_2022::{
+        extension::{
+            transfer_fee::{TransferFee, TransferFeeConfig},
+            BaseStateWithExtensions, StateWithExtensions,
+        },
+        state::Mint,
+    },
+};
+use crate::{error::ErrorCode, utils::apply_bps};
+
+/// Transfer fee a mint charges in the current epoch
+/// (None for SPL Token mints and Token-2022 mints without the extension)
+pub fn current_transfer_fee(mint: &AccountInfo) -> Result<Option<TransferFee>> {
+    if *mint.owner != token_2022::ID {
+        return Ok(None);
+    }
+
+    let data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<Mint>::unpack(&data)?;
+    match mint_state.get_extension::<TransferFeeConfig>() {
+        Ok(config) => Ok(Some(*config.get_epoch_fee(Clock::get()?.epoch))),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Amount to send so that `net_amount` arrives after the transfer fee
+pub fn gross_up_for_fee(fee: Option<&TransferFee>, net_amount: u64) -> Result<u64> {
+    match fee {
+        Some(fee) => fee
+            .calculate_pre_fee_amount(net_amount)
+            .ok_or_else(|| ErrorCode::ArithmeticOverflow.into()),
+        None => Ok(net_amount),
+    }
+}
+
+/// Gross up `net_amount` without sending more than `gross_left`: when rounding
+/// would overshoot the budget, the recipient's net is reduced to fit
+/// Returns the gross amount to send and the net amount it delivers
+pub fn gross_up_within_budget(
+    fee: Option<&TransferFee>,
+    net_amount: u64,
+    gross_left: u64,
+) -> Result<(u64, u64)> {
+    let gross = gross_up_for_fee(fee, net_amount)?;
+    if gross <= gross_left {
+        return Ok((gross, net_amount));
+    }
+
+    let net = match fee {
+        Some(fee) => fee
+            .calculate_post_fee_amount(gross_left)
+            .ok_or(ErrorCode::ArithmeticOverflow)?,
+        None => gross_left,
+    };
+    Ok((gross_left, net))
+}
+
+/// Part of a gross budget that can reach recipients once every transfer pays
+/// the fee rate (the maximum fee only ever lowers the real fee)
+pub fn net_budget_after_fee(fee: Option<&TransferFee>, gross_budget: u64) -> Result<u64> {
+    match fee {
+        Some(fee) => {
+            let fee_bps = u16::from(fee.transfer_fee_basis_points);
+            apply_bps(gross_budget, 10000u16.saturating_sub(fee_bps))
+        }
+        None => Ok(gross_budget),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fee(basis_points: u16, maximum_fee: u64) -> TransferFee {
+        TransferFee {
+            epoch: 0.into(),
+            maximum_fee: maximum_fee.into(),
+            transfer_fee_basis_points: basis_points.into(),
+        }
+    }
+
+    #[test]
+    fn test_no_fee_is_identity() {
+        assert_eq!(gross_up_for_fee(None, 1_000).unwrap(), 1_000);
+        assert_eq!(net_budget_after_fee(None, 1_000).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn test_gross_up_delivers_net_amount() {
+        let fee = fee(100, u64::MAX); // 1%
+
+        for net in [1u64, 99, 990, 123_456] {
+            let gross = gross_up_for_fee(Some(&fee), net).unwrap();
+            assert_eq!(fee.calculate_post_fee_amount(gross).unwrap(), net);
+        }
+    }
+
+    #[test]
+    fn test_net_budget_covers_grossed_up_payouts() {
+        let fee = fee(250, 40); // 2.5%, capped at 40
+        let budget = 100_000u64;
+        let net_budget = net_budget_after_fee(Some(&fee), budget).unwrap();
+        assert_eq!(net_budget, 97_500);
+
+        // Pro-rata nets of 1:2:7 grossed up never spend more than the budget;
+        // rounding is absorbed by trimming the last investor's net
+        let nets = [net_budget / 10, net_budget / 5, net_budget * 7 / 10];
+        let mut spent = 0u64;
+        let mut delivered = Vec::new();
+        for net in nets {
+            let (gross, received) = gross_up_within_budget(Some(&fee), net, budget - spent).unwrap();
+            assert_eq!(fee.calculate_post_fee_amount(gross).unwrap(), received);
+            spent += gross;
+            delivered.push(received);
+        }
+        assert!(spent <= budget);
+        assert_eq!(delivered[..2], nets[..2]);
+        assert!(delivered[2] <= nets[2] && nets[2] - delivered[2] <= nets.len() as u64);
+    }
+
+    #[test]
+    fn test_gross_up_within_budget_trims_net() {
+        let fee = fee(100, u64::MAX); // 1%
+
+        // Fits: the full net is delivered
+        assert_eq!(gross_up_within_budget(Some(&fee), 990, 1_000).unwrap(), (1_000, 990));
+
+        // One short: everything left is sent and the net shrinks to match
+        let (gross, net) = gross_up_within_budget(Some(&fee), 990, 999).unwrap();
+        assert_eq!(gross, 999);
+        assert_eq!(net, fee.calculate_post_fee_amount(999).unwrap());
+        assert!(net < 990);
+
+        // Without a fee the budget caps the amount directly
+        assert_eq!(gross_up_within_budget(None, 500, 400).unwrap(), (400, 400));
+    }
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::state::*;
+use super::{BonfidaVestingSchedule, CustomEscrow, JupiterVestingEscrow, StreamflowStream};
+
+/// Locked-amount view over any supported vesting account
+pub trait VestingSource {
+    /// Mint being vested
+    fn mint(&self) -> Pubkey;
+
+    /// Wallet that funded the vesting, if the layout records one
+    fn sender(&self) -> Option<Pubkey>;
+
+    /// Wallet that receives the vested tokens, if the layout records one
+    fn recipient(&self) -> Option<Pubkey>;
+
+    /// Whether a token account may receive this investor's payouts
+    fn is_payout_account(&self, key: &Pubkey, token_account: &TokenAccount) -> bool;
+
+    /// Amount still locked at a given timestamp
+    fn locked_at(&self, timestamp: i64) -> Result<u64>;
+}
+
+/// Decode a vesting account with the decoder the policy assigns to its owner,
+/// then check it against the policy's vesting mint and allowed senders
+pub fn parse_vesting_source(
+    account_info: &AccountInfo,
+    policy: &PolicyConfig,
+) -> Result<Box<dyn VestingSource>> {
+    // SECURITY: Only accounts owned by an accepted vesting program are decoded
+    let program = policy.vesting_program_for(account_info.owner)?;
+    let data = account_info.try_borrow_data()?;
+
+    let source: Box<dyn VestingSource> = match program.kind {
+        VestingProgramKind::Streamflow => {
+            let stream = StreamflowStream::try_from_bytes(&data)?;
+            stream.validate()?;
+            Box::new(stream)
+        }
+        VestingProgramKind::JupiterLock => Box::new(JupiterVestingEscrow::try_from_bytes(&data)?),
+        VestingProgramKind::BonfidaVesting => Box::new(BonfidaVestingSchedule::try_from_bytes(&data)?),
+        VestingProgramKind::CustomEscrow => Box::new(CustomEscrow::try_from_bytes(&data)?),
+    };
+
+    policy.verify_vesting_source(source.as_ref())?;
+
+    Ok(source)
+}
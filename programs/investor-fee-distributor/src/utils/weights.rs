@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::{error::ErrorCode, state::*};
+use super::{parse_vesting_source, VestingSource};
+
+/// Pro-rata weight of one investor under the policy's weight mode
+pub enum InvestorWeight {
+    /// Locked amount of a vesting account
+    Vesting(Box<dyn VestingSource>),
+    /// Static weight of an investor wallet from the WeightTable
+    Static { investor: Pubkey, weight: u64 },
+}
+
+impl InvestorWeight {
+    /// Load the weight for an investor slot: a vesting account in vesting-locked
+    /// mode, or the investor wallet in static table mode
+    pub fn load(
+        account_info: &AccountInfo,
+        policy: &PolicyConfig,
+        weight_table: Option<&WeightTable>,
+    ) -> Result<Self> {
+        match policy.weight_mode {
+            WeightMode::VestingLocked => Ok(Self::Vesting(parse_vesting_source(account_info, policy)?)),
+            WeightMode::StaticTable => {
+                let table = weight_table.ok_or(ErrorCode::WeightTableRequired)?;
+                Ok(Self::Static {
+                    investor: account_info.key(),
+                    weight: table.weight_of(account_info.key)?,
+                })
+            }
+        }
+    }
+
+    /// Weight at the day's snapshot timestamp
+    pub fn weight_at(&self, timestamp: i64) -> Result<u64> {
+        match self {
+            Self::Vesting(source) => source.locked_at(timestamp),
+            Self::Static { weight, .. } => Ok(*weight),
+        }
+    }
+
+    /// Whether a token account may receive this investor's payouts
+    pub fn is_payout_account(&self, key: &Pubkey, token_account: &TokenAccount) -> bool {
+        match self {
+            Self::Vesting(source) => source.is_payout_account(key, token_account),
+            Self::Static { investor, .. } => token_account.owner == *investor,
+        }
+    }
+
+    /// Wallet that receives this investor's native SOL payouts
+    pub fn recipient(&self) -> Option<Pubkey> {
+        match self {
+            Self::Vesting(source) => source.recipient(),
+            Self::Static { investor, .. } => Some(*investor),
+        }
+    }
+}
+
+/// Calculate the total weight of a page of investor slots at a given timestamp
+pub fn calculate_total_weight(
+    investor_accounts: &[AccountInfo],
+    current_time: i64,
+    policy: &PolicyConfig,
+    weight_table: Option<&WeightTable>,
+) -> Result<u64> {
+    let mut total_weight = 0u64;
+
+    for account_info in investor_accounts.iter() {
+        let weight = InvestorWeight::load(account_info, policy, weight_table)?
+            .weight_at(current_time)?;
+
+        total_weight = total_weight
+            .checked_add(weight)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+    }
+
+    Ok(total_weight)
+}
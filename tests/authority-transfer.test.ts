@@ -0,0 +1,143 @@
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram, Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import { BN } from "bn.js";
+import {
+  setupTestContext,
+  derivePolicyConfigPda,
+  createTokenAccount,
+  TestContext,
+  ONE_SOL,
+} from "./test-helpers";
+
+describe("authority transfer", () => {
+  let ctx: TestContext;
+  let policyConfigPda: PublicKey;
+  const newAuthority = Keypair.generate();
+
+  before(async () => {
+    ctx = await setupTestContext();
+
+    const creatorQuoteAta = await createTokenAccount(
+      ctx.provider,
+      ctx.quoteMint,
+      ctx.payer.publicKey
+    );
+
+    [policyConfigPda] = derivePolicyConfigPda(ctx.program, ctx.vault);
+
+    await ctx.program.methods
+      .initializePolicy(
+        ctx.vault,
+        7000,
+        null,
+        new BN(1000),
+        new BN(1_000_000 * ONE_SOL),
+        creatorQuoteAta
+      )
+      .accounts({
+        policyConfig: policyConfigPda,
+        payer: ctx.payer.publicKey,
+        authority: ctx.payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  });
+
+  it("proposes and cancels a pending authority", async () => {
+    await ctx.program.methods
+      .proposeAuthority(newAuthority.publicKey)
+      .accounts({
+        policyConfig: policyConfigPda,
+        authority: ctx.payer.publicKey,
+      })
+      .rpc();
+
+    let policyConfig = await ctx.program.account.policyConfig.fetch(
+      policyConfigPda
+    );
+    expect(policyConfig.pendingAuthority.toString()).to.equal(
+      newAuthority.publicKey.toString()
+    );
+
+    await ctx.program.methods
+      .cancelAuthorityTransfer()
+      .accounts({
+        policyConfig: policyConfigPda,
+        authority: ctx.payer.publicKey,
+      })
+      .rpc();
+
+    policyConfig = await ctx.program.account.policyConfig.fetch(
+      policyConfigPda
+    );
+    expect(policyConfig.pendingAuthority.toString()).to.equal(
+      PublicKey.default.toString()
+    );
+  });
+
+  it("fails to accept without a pending proposal", async () => {
+    try {
+      await ctx.program.methods
+        .acceptAuthority()
+        .accounts({
+          policyConfig: policyConfigPda,
+          newAuthority: newAuthority.publicKey,
+        })
+        .signers([newAuthority])
+        .rpc();
+
+      expect.fail("Should have failed with NoPendingAuthority");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("NoPendingAuthority");
+    }
+  });
+
+  it("rejects acceptance from a key other than the pending authority", async () => {
+    await ctx.program.methods
+      .proposeAuthority(newAuthority.publicKey)
+      .accounts({
+        policyConfig: policyConfigPda,
+        authority: ctx.payer.publicKey,
+      })
+      .rpc();
+
+    const impostor = Keypair.generate();
+
+    try {
+      await ctx.program.methods
+        .acceptAuthority()
+        .accounts({
+          policyConfig: policyConfigPda,
+          newAuthority: impostor.publicKey,
+        })
+        .signers([impostor])
+        .rpc();
+
+      expect.fail("Should have failed with InvalidPendingAuthority");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidPendingAuthority");
+    }
+  });
+
+  it("transfers authority once the pending authority accepts", async () => {
+    await ctx.program.methods
+      .acceptAuthority()
+      .accounts({
+        policyConfig: policyConfigPda,
+        newAuthority: newAuthority.publicKey,
+      })
+      .signers([newAuthority])
+      .rpc();
+
+    const policyConfig = await ctx.program.account.policyConfig.fetch(
+      policyConfigPda
+    );
+    expect(policyConfig.authority.toString()).to.equal(
+      newAuthority.publicKey.toString()
+    );
+    expect(policyConfig.pendingAuthority.toString()).to.equal(
+      PublicKey.default.toString()
+    );
+  });
+});
@@ -0,0 +1,244 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, Idl } from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+import { BN } from "bn.js";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  setupTestContext,
+  derivePolicyConfigPda,
+  deriveDailyProgressPda,
+  deriveGuardianConfigPda,
+  deriveInvestorFeePositionOwnerPda,
+  createTokenAccount,
+  mintTokensTo,
+  getTokenBalance,
+  registerInvestors,
+  requireHonoraryPosition,
+  snapshotLockedTotal,
+  TestContext,
+  TestInvestor,
+  ONE_SOL,
+} from "./test-helpers";
+
+describe("base_fee_swap", () => {
+  let ctx: TestContext;
+  let creatorQuoteAta: PublicKey;
+  let policyConfigPda: PublicKey;
+  let dailyProgressPda: PublicKey;
+  let positionOwnerPda: PublicKey;
+  let registryPagePda: PublicKey;
+  let investors: TestInvestor[];
+
+  before(async () => {
+    ctx = await setupTestContext();
+
+    creatorQuoteAta = await createTokenAccount(
+      ctx.provider,
+      ctx.quoteMint,
+      ctx.payer.publicKey
+    );
+
+    [policyConfigPda] = derivePolicyConfigPda(ctx.program, ctx.vault);
+    [dailyProgressPda] = deriveDailyProgressPda(ctx.program, ctx.vault);
+    [positionOwnerPda] = deriveInvestorFeePositionOwnerPda(
+      ctx.program,
+      ctx.vault
+    );
+
+    await ctx.program.methods
+      .initializePolicy(
+        ctx.vault,
+        7000,
+        new BN(10 * ONE_SOL),
+        new BN(1000),
+        new BN(1_000_000 * ONE_SOL),
+        creatorQuoteAta
+      )
+      .accounts({
+        policyConfig: policyConfigPda,
+        payer: ctx.payer.publicKey,
+        authority: ctx.payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    ({ investors, registryPage: registryPagePda } = await registerInvestors(
+      ctx,
+      policyConfigPda,
+      [100_000 * ONE_SOL, 200_000 * ONE_SOL]
+    ));
+  });
+
+  async function setBaseFeeSwap(
+    maxSlippageBps: number,
+    minSwapPrice: BN
+  ): Promise<string> {
+    return await ctx.program.methods
+      .setBaseFeeSwap(true, maxSlippageBps, new BN(1_000), minSwapPrice)
+      .accounts({
+        policyConfig: policyConfigPda,
+        dailyProgress: dailyProgressPda,
+        investorFeePositionOwner: positionOwnerPda,
+        authority: ctx.payer.publicKey,
+      })
+      .rpc();
+  }
+
+  // Claim and distribute through the honorary DAMM v1 lock escrow; the pool,
+  // vault and escrow accounts are read from the position and pool state
+  async function crankDistributionFull(
+    treasuryQuoteAta: PublicKey,
+    treasuryBaseAta: PublicKey
+  ): Promise<string> {
+    const dynamicAmm = new Program(
+      require("../idls/dynamic_amm.json") as Idl,
+      ctx.provider
+    );
+    const dynamicVault = new Program(
+      require("../idls/dynamic_vault.json") as Idl,
+      ctx.provider
+    );
+
+    const owner = await ctx.program.account.investorFeePositionOwner.fetch(
+      positionOwnerPda
+    );
+    const pool: any = await dynamicAmm.account.pool.fetch(owner.pool);
+    const lockEscrow: any = await dynamicAmm.account.lockEscrow.fetch(
+      owner.position
+    );
+    const aVault: any = await dynamicVault.account.vault.fetch(pool.aVault);
+    const bVault: any = await dynamicVault.account.vault.fetch(pool.bVault);
+
+    return await ctx.program.methods
+      .crankDistributionFull(
+        investors.map((inv, index) => ({ index, quoteAta: inv.quoteAta }))
+      )
+      .accounts({
+        cranker: ctx.payer.publicKey,
+        dailyProgress: dailyProgressPda,
+        policyConfig: policyConfigPda,
+        guardianConfig: deriveGuardianConfigPda(ctx.program)[0],
+        investorFeePositionOwner: positionOwnerPda,
+        investorRegistryPage: registryPagePda,
+        weightTable: null,
+        pool: owner.pool,
+        lpMint: pool.lpMint,
+        lockEscrow: owner.position,
+        escrowVault: lockEscrow.escrowVault,
+        aVault: pool.aVault,
+        bVault: pool.bVault,
+        aVaultLp: pool.aVaultLp,
+        bVaultLp: pool.bVaultLp,
+        aVaultLpMint: aVault.lpMint,
+        bVaultLpMint: bVault.lpMint,
+        aTokenVault: aVault.tokenVault,
+        bTokenVault: bVault.tokenVault,
+        dynamicAmmProgram: dynamicAmm.programId,
+        dynamicVault: dynamicVault.programId,
+        protocolTokenFee: pool.protocolTokenAFee,
+        quoteMint: ctx.quoteMint,
+        treasuryQuoteAta,
+        treasuryBaseAta,
+        creatorQuoteAta,
+        nativeUnwrapAccount: null,
+        creatorWallet: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts([
+        ...investors.map((inv) => ({
+          pubkey: inv.stream,
+          isSigner: false,
+          isWritable: false,
+        })),
+        ...investors.map((inv) => ({
+          pubkey: inv.quoteAta,
+          isSigner: false,
+          isWritable: true,
+        })),
+      ])
+      .rpc();
+  }
+
+  it("configures base fee swaps", async () => {
+    await setBaseFeeSwap(100, new BN(1_900_000_000));
+
+    const policyConfig = await ctx.program.account.policyConfig.fetch(
+      policyConfigPda
+    );
+    expect(policyConfig.swapBaseFees).to.be.true;
+    expect(policyConfig.maxSwapSlippageBps).to.equal(100);
+    expect(policyConfig.minBaseSwapAmount.toNumber()).to.equal(1_000);
+    expect(policyConfig.minSwapPrice.toNumber()).to.equal(1_900_000_000);
+  });
+
+  it("rejects base fee swap slippage above 100%", async () => {
+    try {
+      await setBaseFeeSwap(10001, new BN(1_900_000_000));
+
+      expect.fail("Should have failed with InvalidSlippageBps");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidSlippageBps");
+    }
+  });
+
+  it("rejects base fee swaps without a price floor", async () => {
+    try {
+      await setBaseFeeSwap(100, new BN(0));
+
+      expect.fail("Should have failed with SwapPriceFloorRequired");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("SwapPriceFloorRequired");
+    }
+  });
+
+  it("rejects a swap returning less than the price floor", async function () {
+    await requireHonoraryPosition(this, ctx, positionOwnerPda);
+
+    const treasuryQuoteAta = await getAssociatedTokenAddress(
+      ctx.quoteMint,
+      positionOwnerPda,
+      true
+    );
+    const treasuryBaseAta = await getAssociatedTokenAddress(
+      ctx.baseMint,
+      positionOwnerPda,
+      true
+    );
+
+    // Full slippage tolerance, so only a floor of 1e9 quote per base atom
+    // (far above any pool price) can stop the swap
+    await setBaseFeeSwap(10000, new BN("1000000000000000000"));
+
+    const baseFees = ONE_SOL;
+    await mintTokensTo(ctx.provider, ctx.baseMint, treasuryBaseAta, baseFees);
+
+    await snapshotLockedTotal(
+      ctx,
+      policyConfigPda,
+      dailyProgressPda,
+      positionOwnerPda,
+      registryPagePda,
+      investors.map((inv) => inv.stream)
+    );
+
+    try {
+      await crankDistributionFull(treasuryQuoteAta, treasuryBaseAta);
+
+      expect.fail("Should have failed with ExceededSlippage");
+    } catch (err) {
+      expect((err.logs ?? []).join("\n")).to.include("ExceededSlippage");
+    }
+
+    // Nothing was swapped or paid
+    expect(
+      (await getTokenBalance(ctx.provider, treasuryBaseAta)) >= BigInt(baseFees)
+    ).to.be.true;
+    const progress = await ctx.program.account.dailyProgress.fetch(
+      dailyProgressPda
+    );
+    expect(progress.currentPage).to.equal(0);
+    expect(progress.isFinalized).to.be.false;
+  });
+});
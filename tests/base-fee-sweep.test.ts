@@ -0,0 +1,216 @@
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram, Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import { BN } from "bn.js";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  setupTestContext,
+  derivePolicyConfigPda,
+  deriveDailyProgressPda,
+  deriveGuardianConfigPda,
+  deriveInvestorFeePositionOwnerPda,
+  createTokenAccount,
+  mintTokensTo,
+  getTokenBalance,
+  registerInvestors,
+  requireHonoraryPosition,
+  snapshotLockedTotal,
+  crankDistribution,
+  TestContext,
+  TestInvestor,
+  ONE_SOL,
+} from "./test-helpers";
+
+describe("sweep_base_fees", () => {
+  let ctx: TestContext;
+  let creatorQuoteAta: PublicKey;
+  let baseFeeDestination: PublicKey;
+  let policyConfigPda: PublicKey;
+  let dailyProgressPda: PublicKey;
+  let positionOwnerPda: PublicKey;
+  let registryPagePda: PublicKey;
+  let treasuryQuoteAta: PublicKey;
+  let treasuryBaseAta: PublicKey;
+  let investors: TestInvestor[];
+
+  const BASE_FEES = 5 * ONE_SOL;
+
+  before(async () => {
+    ctx = await setupTestContext();
+
+    creatorQuoteAta = await createTokenAccount(
+      ctx.provider,
+      ctx.quoteMint,
+      ctx.payer.publicKey
+    );
+    baseFeeDestination = await createTokenAccount(
+      ctx.provider,
+      ctx.baseMint,
+      ctx.payer.publicKey
+    );
+
+    [policyConfigPda] = derivePolicyConfigPda(ctx.program, ctx.vault);
+    [dailyProgressPda] = deriveDailyProgressPda(ctx.program, ctx.vault);
+    [positionOwnerPda] = deriveInvestorFeePositionOwnerPda(
+      ctx.program,
+      ctx.vault
+    );
+    treasuryQuoteAta = await getAssociatedTokenAddress(
+      ctx.quoteMint,
+      positionOwnerPda,
+      true
+    );
+    treasuryBaseAta = await getAssociatedTokenAddress(
+      ctx.baseMint,
+      positionOwnerPda,
+      true
+    );
+
+    await ctx.program.methods
+      .initializePolicy(
+        ctx.vault,
+        7000,
+        null,
+        new BN(1000),
+        new BN(1_000_000 * ONE_SOL),
+        creatorQuoteAta
+      )
+      .accounts({
+        policyConfig: policyConfigPda,
+        payer: ctx.payer.publicKey,
+        authority: ctx.payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    ({ investors, registryPage: registryPagePda } = await registerInvestors(
+      ctx,
+      policyConfigPda,
+      [100_000 * ONE_SOL, 200_000 * ONE_SOL]
+    ));
+  });
+
+  async function sweep(authority: Keypair = ctx.payer): Promise<string> {
+    return await ctx.program.methods
+      .sweepBaseFees()
+      .accounts({
+        policyConfig: policyConfigPda,
+        guardianConfig: deriveGuardianConfigPda(ctx.program)[0],
+        investorFeePositionOwner: positionOwnerPda,
+        baseMint: ctx.baseMint,
+        treasuryBaseAta,
+        destination: baseFeeDestination,
+        authority: authority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers(authority === ctx.payer ? [] : [authority])
+      .rpc();
+  }
+
+  it("sets the base fee destination and quarantine mode", async () => {
+    await ctx.program.methods
+      .setBaseFeeHandling(baseFeeDestination, true)
+      .accounts({
+        policyConfig: policyConfigPda,
+        investorFeePositionOwner: positionOwnerPda,
+        authority: ctx.payer.publicKey,
+      })
+      .rpc();
+
+    const policyConfig = await ctx.program.account.policyConfig.fetch(
+      policyConfigPda
+    );
+    expect(policyConfig.baseFeeDestination.toBase58()).to.equal(
+      baseFeeDestination.toBase58()
+    );
+    expect(policyConfig.quarantineBaseFees).to.be.true;
+  });
+
+  it("cranks with base fees quarantined and leaves them in the treasury", async function () {
+    await requireHonoraryPosition(this, ctx, positionOwnerPda);
+
+    await mintTokensTo(ctx.provider, ctx.baseMint, treasuryBaseAta, BASE_FEES);
+    await mintTokensTo(
+      ctx.provider,
+      ctx.quoteMint,
+      treasuryQuoteAta,
+      10 * ONE_SOL
+    );
+
+    await snapshotLockedTotal(
+      ctx,
+      policyConfigPda,
+      dailyProgressPda,
+      positionOwnerPda,
+      registryPagePda,
+      investors.map((inv) => inv.stream)
+    );
+    await crankDistribution(
+      ctx,
+      {
+        policyConfig: policyConfigPda,
+        dailyProgress: dailyProgressPda,
+        investorFeePositionOwner: positionOwnerPda,
+        investorRegistryPage: registryPagePda,
+        treasuryQuoteAta,
+        treasuryBaseAta,
+        creatorQuoteAta,
+      },
+      investors
+    );
+
+    // The day is distributed instead of failing with BaseFeesDetected
+    const progress = await ctx.program.account.dailyProgress.fetch(
+      dailyProgressPda
+    );
+    expect(progress.isFinalized).to.be.true;
+    for (const investor of investors) {
+      expect(
+        await getTokenBalance(ctx.provider, investor.quoteAta) > 0n
+      ).to.be.true;
+    }
+
+    // Base tokens stay untouched until swept
+    expect(await getTokenBalance(ctx.provider, treasuryBaseAta)).to.equal(
+      BigInt(BASE_FEES)
+    );
+  });
+
+  it("fails when a non-authority sweeps", async function () {
+    await requireHonoraryPosition(this, ctx, positionOwnerPda);
+
+    try {
+      await sweep(Keypair.generate());
+      expect.fail("Should have failed with Unauthorized");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("Unauthorized");
+    }
+  });
+
+  it("sweeps quarantined base fees to the destination", async function () {
+    await requireHonoraryPosition(this, ctx, positionOwnerPda);
+
+    const destinationBefore = await getTokenBalance(
+      ctx.provider,
+      baseFeeDestination
+    );
+    await sweep();
+
+    expect(await getTokenBalance(ctx.provider, treasuryBaseAta)).to.equal(0n);
+    expect(
+      (await getTokenBalance(ctx.provider, baseFeeDestination)) -
+        destinationBefore
+    ).to.equal(BigInt(BASE_FEES));
+  });
+
+  it("fails to sweep an empty treasury", async function () {
+    await requireHonoraryPosition(this, ctx, positionOwnerPda);
+
+    try {
+      await sweep();
+      expect.fail("Should have failed with NoBaseFeesToSweep");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("NoBaseFeesToSweep");
+    }
+  });
+});
@@ -0,0 +1,180 @@
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram, Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import { BN } from "bn.js";
+import { getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  setupTestContext,
+  derivePolicyConfigPda,
+  deriveDailyProgressPda,
+  deriveInvestorFeePositionOwnerPda,
+  createTokenAccount,
+  mintTokensTo,
+  registerInvestors,
+  requireHonoraryPosition,
+  snapshotLockedTotal,
+  crankDistribution,
+  TestContext,
+  TestInvestor,
+  ONE_SOL,
+  ONE_DAY,
+} from "./test-helpers";
+
+describe("catch_up_missed_days", () => {
+  let ctx: TestContext;
+  let creatorQuoteAta: PublicKey;
+  let policyConfigPda: PublicKey;
+  let dailyProgressPda: PublicKey;
+  let positionOwnerPda: PublicKey;
+  let registryPagePda: PublicKey;
+  let investors: TestInvestor[];
+
+  before(async () => {
+    ctx = await setupTestContext();
+
+    creatorQuoteAta = await createTokenAccount(
+      ctx.provider,
+      ctx.quoteMint,
+      ctx.payer.publicKey
+    );
+
+    [policyConfigPda] = derivePolicyConfigPda(ctx.program, ctx.vault);
+    [dailyProgressPda] = deriveDailyProgressPda(ctx.program, ctx.vault);
+    [positionOwnerPda] = deriveInvestorFeePositionOwnerPda(
+      ctx.program,
+      ctx.vault
+    );
+
+    await ctx.program.methods
+      .initializePolicy(
+        ctx.vault,
+        7000,
+        null,
+        new BN(1000),
+        new BN(1_000_000 * ONE_SOL),
+        creatorQuoteAta
+      )
+      .accounts({
+        policyConfig: policyConfigPda,
+        payer: ctx.payer.publicKey,
+        authority: ctx.payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    ({ investors, registryPage: registryPagePda } = await registerInvestors(
+      ctx,
+      policyConfigPda,
+      [100_000 * ONE_SOL, 200_000 * ONE_SOL]
+    ));
+  });
+
+  it("toggles catching up missed days", async () => {
+    await ctx.program.methods
+      .setCatchUpMissedDays(true)
+      .accounts({
+        policyConfig: policyConfigPda,
+        dailyProgress: dailyProgressPda,
+        authority: ctx.payer.publicKey,
+      })
+      .rpc();
+
+    const policyConfig = await ctx.program.account.policyConfig.fetch(
+      policyConfigPda
+    );
+    expect(policyConfig.catchUpMissedDays).to.be.true;
+  });
+
+  it("fails when a non-authority toggles catch-up", async () => {
+    const attacker = Keypair.generate();
+
+    try {
+      await ctx.program.methods
+        .setCatchUpMissedDays(false)
+        .accounts({
+          policyConfig: policyConfigPda,
+          dailyProgress: dailyProgressPda,
+          authority: attacker.publicKey,
+        })
+        .signers([attacker])
+        .rpc();
+
+      expect.fail("Should have failed with Unauthorized");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("Unauthorized");
+    }
+  });
+
+  it("splits claimed fees evenly over the days of the run", async function () {
+    await requireHonoraryPosition(this, ctx, positionOwnerPda);
+
+    const treasuryQuoteAta = await getAssociatedTokenAddress(
+      ctx.quoteMint,
+      positionOwnerPda,
+      true
+    );
+    const treasuryBaseAta = await getAssociatedTokenAddress(
+      ctx.baseMint,
+      positionOwnerPda,
+      true
+    );
+
+    const poolBefore = (await ctx.program.account.dailyProgress.fetchNullable(
+      dailyProgressPda
+    ))?.catchUpPool ?? new BN(0);
+
+    const fees = 70 * ONE_SOL + 1;
+    await mintTokensTo(ctx.provider, ctx.quoteMint, treasuryQuoteAta, fees);
+
+    const streams = investors.map((inv) => inv.stream);
+    await snapshotLockedTotal(
+      ctx,
+      policyConfigPda,
+      dailyProgressPda,
+      positionOwnerPda,
+      registryPagePda,
+      streams
+    );
+
+    let progress = await ctx.program.account.dailyProgress.fetch(
+      dailyProgressPda
+    );
+    const daysLeft =
+      Math.max(progress.catchUpThroughDay.toNumber(), progress.dayId.toNumber()) -
+      progress.dayId.toNumber() +
+      1;
+
+    // Days processed during a catch-up run are evaluated at their own window start
+    if (daysLeft > 1) {
+      expect(progress.snapshotTs.toNumber()).to.equal(
+        progress.dayId.toNumber() * ONE_DAY
+      );
+    }
+
+    await crankDistribution(
+      ctx,
+      {
+        policyConfig: policyConfigPda,
+        dailyProgress: dailyProgressPda,
+        investorFeePositionOwner: positionOwnerPda,
+        investorRegistryPage: registryPagePda,
+        treasuryQuoteAta,
+        treasuryBaseAta,
+        creatorQuoteAta,
+      },
+      investors
+    );
+
+    // The processed day takes its even part; the rest (and the rounding) waits
+    // in the catch-up pool for the run's remaining days
+    progress = await ctx.program.account.dailyProgress.fetch(dailyProgressPda);
+    const runFees = poolBefore.add(new BN(fees));
+    const dayFees = runFees.div(new BN(daysLeft));
+    expect(progress.totalQuoteClaimedToday.toString()).to.equal(
+      dayFees.toString()
+    );
+    expect(progress.catchUpPool.toString()).to.equal(
+      runFees.sub(dayFees).toString()
+    );
+  });
+});
@@ -0,0 +1,105 @@
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram, Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import { BN } from "bn.js";
+import {
+  setupTestContext,
+  derivePolicyConfigPda,
+  deriveDailyProgressPda,
+  createTokenAccount,
+  TestContext,
+  ONE_SOL,
+} from "./test-helpers";
+
+describe("set_distribution_mode", () => {
+  let ctx: TestContext;
+  let policyConfigPda: PublicKey;
+  let dailyProgressPda: PublicKey;
+
+  before(async () => {
+    ctx = await setupTestContext();
+
+    const creatorQuoteAta = await createTokenAccount(
+      ctx.provider,
+      ctx.quoteMint,
+      ctx.payer.publicKey
+    );
+
+    [policyConfigPda] = derivePolicyConfigPda(ctx.program, ctx.vault);
+    [dailyProgressPda] = deriveDailyProgressPda(ctx.program, ctx.vault);
+
+    await ctx.program.methods
+      .initializePolicy(
+        ctx.vault,
+        7000,
+        null,
+        new BN(1000),
+        new BN(1_000_000 * ONE_SOL),
+        creatorQuoteAta
+      )
+      .accounts({
+        policyConfig: policyConfigPda,
+        payer: ctx.payer.publicKey,
+        authority: ctx.payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  });
+
+  it("defaults to push and switches to pull", async () => {
+    let policyConfig = await ctx.program.account.policyConfig.fetch(
+      policyConfigPda
+    );
+    expect(policyConfig.distributionMode).to.deep.equal({ push: {} });
+
+    await ctx.program.methods
+      .setDistributionMode({ pull: {} })
+      .accounts({
+        policyConfig: policyConfigPda,
+        dailyProgress: dailyProgressPda,
+        authority: ctx.payer.publicKey,
+      })
+      .rpc();
+
+    policyConfig = await ctx.program.account.policyConfig.fetch(
+      policyConfigPda
+    );
+    expect(policyConfig.distributionMode).to.deep.equal({ pull: {} });
+  });
+
+  it("switches to merkle", async () => {
+    await ctx.program.methods
+      .setDistributionMode({ merkle: {} })
+      .accounts({
+        policyConfig: policyConfigPda,
+        dailyProgress: dailyProgressPda,
+        authority: ctx.payer.publicKey,
+      })
+      .rpc();
+
+    const policyConfig = await ctx.program.account.policyConfig.fetch(
+      policyConfigPda
+    );
+    expect(policyConfig.distributionMode).to.deep.equal({ merkle: {} });
+  });
+
+  it("fails when signed by a non-authority", async () => {
+    const attacker = Keypair.generate();
+
+    try {
+      await ctx.program.methods
+        .setDistributionMode({ push: {} })
+        .accounts({
+          policyConfig: policyConfigPda,
+          dailyProgress: dailyProgressPda,
+          authority: attacker.publicKey,
+        })
+        .signers([attacker])
+        .rpc();
+
+      expect.fail("Should have failed with Unauthorized");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("Unauthorized");
+    }
+  });
+});
@@ -15,10 +15,14 @@ import {
   derivePolicyConfigPda,
   deriveInvestorFeePositionOwnerPda,
   deriveDailyProgressPda,
+  deriveGuardianConfigPda,
   createTokenAccount,
   mintTokensTo,
   getTokenBalance,
   createMockStreamflowStream,
+  setInvestorRegistryPage,
+  setVestingFilter,
+  snapshotLockedTotal,
   TestContext,
   ONE_SOL,
   ONE_DAY,
@@ -33,6 +37,16 @@ describe("End-to-End Integration Tests", () => {
   let policyConfigPda: PublicKey;
   let positionOwnerPda: PublicKey;
   let dailyProgressPda: PublicKey;
+  let registryPagePda: PublicKey;
+  let treasuryQuoteAta: PublicKey;
+  let treasuryBaseAta: PublicKey;
+  const investors: {
+    keypair: Keypair;
+    stream: PublicKey;
+    quoteAta: PublicKey;
+    depositedAmount: number;
+    lockedAmount: number;
+  }[] = [];
 
   before(async () => {
     ctx = await setupTestContext();
@@ -91,7 +105,6 @@ describe("End-to-End Integration Tests", () => {
     console.log("\n=== STEP 2: Create Mock Investors ===");
 
     const NUM_INVESTORS = 5;
-    const investors = [];
 
     for (let i = 0; i < NUM_INVESTORS; i++) {
       const investorKeypair = Keypair.generate();
@@ -128,16 +141,32 @@ describe("End-to-End Integration Tests", () => {
     console.log(`\nTotal deposited: ${investors.reduce((sum, inv) => sum + inv.depositedAmount, 0) / ONE_SOL} tokens`);
     console.log(`Total locked: ${investors.reduce((sum, inv) => sum + inv.lockedAmount, 0) / ONE_SOL} tokens`);
 
+    // Registry pages list streams in ascending order
+    investors.sort((a, b) => Buffer.compare(a.stream.toBuffer(), b.stream.toBuffer()));
+
+    // Register all investors on a single registry page
+    registryPagePda = await setInvestorRegistryPage(
+      ctx,
+      policyConfigPda,
+      0,
+      investors.map((inv) => ({ stream: inv.stream, quoteAta: inv.quoteAta }))
+    );
+
+    // Only streams vesting the base mint from the vault's sender count
+    await setVestingFilter(ctx, policyConfigPda, ctx.baseMint, [
+      ctx.payer.publicKey,
+    ]);
+
     console.log("\n=== STEP 3: Simulate Fee Accumulation ===");
 
     // Create treasury ATAs
-    const treasuryQuoteAta = await getAssociatedTokenAddress(
+    treasuryQuoteAta = await getAssociatedTokenAddress(
       ctx.quoteMint,
       positionOwnerPda,
       true
     );
 
-    const treasuryBaseAta = await getAssociatedTokenAddress(
+    treasuryBaseAta = await getAssociatedTokenAddress(
       ctx.baseMint,
       positionOwnerPda,
       true
@@ -218,16 +247,31 @@ describe("End-to-End Integration Tests", () => {
     }));
 
     try {
+      await snapshotLockedTotal(
+        ctx,
+        policyConfigPda,
+        dailyProgressPda,
+        positionOwnerPda,
+        registryPagePda,
+        investors.map((inv) => inv.stream)
+      );
+
       const crankTx = await ctx.program.methods
-        .crankDistribution(1, investorData) // Single page
+        .crankDistribution(investorData) // Single page
         .accounts({
           cranker: ctx.payer.publicKey,
           dailyProgress: dailyProgressPda,
           policyConfig: policyConfigPda,
+          guardianConfig: deriveGuardianConfigPda(ctx.program)[0],
           investorFeePositionOwner: positionOwnerPda,
+          investorRegistryPage: registryPagePda,
+          weightTable: null,
+          quoteMint: ctx.quoteMint,
           treasuryQuoteAta: treasuryQuoteAta,
           treasuryBaseAta: treasuryBaseAta,
           creatorQuoteAta: creatorQuoteAta,
+          nativeUnwrapAccount: null,
+          creatorWallet: null,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
@@ -296,6 +340,65 @@ describe("End-to-End Integration Tests", () => {
     }
   });
 
+  it("Paused vault rejects the crank", async () => {
+    await ctx.program.methods
+      .pause()
+      .accounts({
+        policyConfig: policyConfigPda,
+        authority: ctx.payer.publicKey,
+      })
+      .rpc();
+
+    try {
+      await ctx.program.methods
+        .crankDistribution(
+          investors.map((inv) => ({ lockedAmount: new BN(inv.lockedAmount) }))
+        )
+        .accounts({
+          cranker: ctx.payer.publicKey,
+          dailyProgress: dailyProgressPda,
+          policyConfig: policyConfigPda,
+          guardianConfig: deriveGuardianConfigPda(ctx.program)[0],
+          investorFeePositionOwner: positionOwnerPda,
+          investorRegistryPage: registryPagePda,
+          weightTable: null,
+          quoteMint: ctx.quoteMint,
+          treasuryQuoteAta: treasuryQuoteAta,
+          treasuryBaseAta: treasuryBaseAta,
+          creatorQuoteAta: creatorQuoteAta,
+          nativeUnwrapAccount: null,
+          creatorWallet: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          ...investors.map((inv) => ({
+            pubkey: inv.stream,
+            isSigner: false,
+            isWritable: false,
+          })),
+          ...investors.map((inv) => ({
+            pubkey: inv.quoteAta,
+            isSigner: false,
+            isWritable: true,
+          })),
+        ])
+        .rpc();
+
+      expect.fail("Should have failed with DistributionPaused");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("DistributionPaused");
+    } finally {
+      await ctx.program.methods
+        .unpause()
+        .accounts({
+          policyConfig: policyConfigPda,
+          authority: ctx.payer.publicKey,
+        })
+        .rpc();
+    }
+  });
+
   it("Multi-day distribution workflow", async () => {
     console.log("\n=== Testing multi-day distributions ===");
     console.log("This test would simulate multiple 24h periods");
@@ -0,0 +1,188 @@
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+import { BN } from "bn.js";
+import {
+  NATIVE_MINT,
+  createSyncNativeInstruction,
+  getAssociatedTokenAddress,
+} from "@solana/spl-token";
+import {
+  setupTestContext,
+  derivePolicyConfigPda,
+  deriveDailyProgressPda,
+  deriveInvestorFeePositionOwnerPda,
+  deriveNativeUnwrapPda,
+  createTokenAccount,
+  getTokenBalance,
+  registerInvestors,
+  requireHonoraryPosition,
+  snapshotLockedTotal,
+  crankDistribution,
+  TestContext,
+  TestInvestor,
+  ONE_SOL,
+} from "./test-helpers";
+
+describe("native_sol_payouts", () => {
+  let ctx: TestContext;
+  let creatorQuoteAta: PublicKey;
+  let policyConfigPda: PublicKey;
+  let dailyProgressPda: PublicKey;
+  let positionOwnerPda: PublicKey;
+  let registryPagePda: PublicKey;
+  let investors: TestInvestor[];
+
+  const DEPOSITS = [100_000 * ONE_SOL, 300_000 * ONE_SOL];
+
+  before(async () => {
+    // Native payouts only apply to a wSOL quote mint
+    ctx = { ...(await setupTestContext()), quoteMint: NATIVE_MINT };
+
+    creatorQuoteAta = await createTokenAccount(
+      ctx.provider,
+      ctx.quoteMint,
+      ctx.payer.publicKey
+    );
+
+    [policyConfigPda] = derivePolicyConfigPda(ctx.program, ctx.vault);
+    [dailyProgressPda] = deriveDailyProgressPda(ctx.program, ctx.vault);
+    [positionOwnerPda] = deriveInvestorFeePositionOwnerPda(
+      ctx.program,
+      ctx.vault
+    );
+
+    await ctx.program.methods
+      .initializePolicy(
+        ctx.vault,
+        7000,
+        null,
+        new BN(1000),
+        new BN(DEPOSITS.reduce((sum, amount) => sum + amount, 0)),
+        creatorQuoteAta
+      )
+      .accounts({
+        policyConfig: policyConfigPda,
+        payer: ctx.payer.publicKey,
+        authority: ctx.payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    ({ investors, registryPage: registryPagePda } = await registerInvestors(
+      ctx,
+      policyConfigPda,
+      DEPOSITS
+    ));
+  });
+
+  it("toggles native SOL payouts", async () => {
+    await ctx.program.methods
+      .setNativeSolPayouts(true)
+      .accounts({
+        policyConfig: policyConfigPda,
+        dailyProgress: dailyProgressPda,
+        authority: ctx.payer.publicKey,
+      })
+      .rpc();
+
+    const policyConfig = await ctx.program.account.policyConfig.fetch(
+      policyConfigPda
+    );
+    expect(policyConfig.payNativeSol).to.be.true;
+  });
+
+  it("unwraps the day's share and pays recipients and the creator in lamports", async function () {
+    await requireHonoraryPosition(this, ctx, positionOwnerPda);
+
+    const treasuryQuoteAta = await getAssociatedTokenAddress(
+      ctx.quoteMint,
+      positionOwnerPda,
+      true
+    );
+    const treasuryBaseAta = await getAssociatedTokenAddress(
+      ctx.baseMint,
+      positionOwnerPda,
+      true
+    );
+    const [nativeUnwrapPda] = deriveNativeUnwrapPda(ctx.program, ctx.vault);
+
+    // Simulate fees by wrapping SOL into the treasury
+    const fees = 10 * ONE_SOL;
+    await ctx.provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: ctx.payer.publicKey,
+          toPubkey: treasuryQuoteAta,
+          lamports: fees,
+        }),
+        createSyncNativeInstruction(treasuryQuoteAta)
+      )
+    );
+
+    const walletsBefore = await Promise.all(
+      investors.map((inv) =>
+        ctx.provider.connection.getBalance(inv.keypair.publicKey)
+      )
+    );
+    const creatorAtaBefore = await getTokenBalance(
+      ctx.provider,
+      creatorQuoteAta
+    );
+
+    await snapshotLockedTotal(
+      ctx,
+      policyConfigPda,
+      dailyProgressPda,
+      positionOwnerPda,
+      registryPagePda,
+      investors.map((inv) => inv.stream)
+    );
+    await crankDistribution(
+      ctx,
+      {
+        policyConfig: policyConfigPda,
+        dailyProgress: dailyProgressPda,
+        investorFeePositionOwner: positionOwnerPda,
+        investorRegistryPage: registryPagePda,
+        treasuryQuoteAta,
+        treasuryBaseAta,
+        creatorQuoteAta,
+        nativeUnwrapAccount: nativeUnwrapPda,
+        creatorWallet: ctx.payer.publicKey,
+      },
+      investors,
+      investors.map((inv) => inv.keypair.publicKey)
+    );
+
+    const progress = await ctx.program.account.dailyProgress.fetch(
+      dailyProgressPda
+    );
+    expect(progress.isFinalized).to.be.true;
+
+    // Recipients' wallets receive lamports; their wSOL accounts stay empty
+    let paid = 0;
+    for (let i = 0; i < investors.length; i++) {
+      const wallet = await ctx.provider.connection.getBalance(
+        investors[i].keypair.publicKey
+      );
+      expect(wallet).to.be.greaterThan(walletsBefore[i]);
+      paid += wallet - walletsBefore[i];
+      expect(
+        await getTokenBalance(ctx.provider, investors[i].quoteAta)
+      ).to.equal(0n);
+    }
+    expect(paid).to.equal(progress.investorDistributedToday.toNumber());
+
+    // The creator remainder follows the same option
+    expect(progress.creatorDistributedToday.toNumber()).to.be.greaterThan(0);
+    expect(await getTokenBalance(ctx.provider, creatorQuoteAta)).to.equal(
+      creatorAtaBefore
+    );
+
+    // The temporary unwrap account is closed and the treasury is drained
+    expect(await ctx.provider.connection.getAccountInfo(nativeUnwrapPda)).to.be
+      .null;
+    expect(await getTokenBalance(ctx.provider, treasuryQuoteAta)).to.equal(0n);
+  });
+});
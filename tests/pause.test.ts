@@ -0,0 +1,143 @@
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram, Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import { BN } from "bn.js";
+import {
+  setupTestContext,
+  derivePolicyConfigPda,
+  deriveGuardianConfigPda,
+  createTokenAccount,
+  TestContext,
+  ONE_SOL,
+} from "./test-helpers";
+
+const BPF_LOADER_UPGRADEABLE_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+
+describe("pause", () => {
+  let ctx: TestContext;
+  let policyConfigPda: PublicKey;
+
+  before(async () => {
+    ctx = await setupTestContext();
+
+    const creatorQuoteAta = await createTokenAccount(
+      ctx.provider,
+      ctx.quoteMint,
+      ctx.payer.publicKey
+    );
+
+    [policyConfigPda] = derivePolicyConfigPda(ctx.program, ctx.vault);
+
+    await ctx.program.methods
+      .initializePolicy(
+        ctx.vault,
+        7000,
+        null,
+        new BN(1000),
+        new BN(1_000_000 * ONE_SOL),
+        creatorQuoteAta
+      )
+      .accounts({
+        policyConfig: policyConfigPda,
+        payer: ctx.payer.publicKey,
+        authority: ctx.payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  });
+
+  it("pauses and unpauses a vault", async () => {
+    await ctx.program.methods
+      .pause()
+      .accounts({
+        policyConfig: policyConfigPda,
+        authority: ctx.payer.publicKey,
+      })
+      .rpc();
+
+    let policyConfig = await ctx.program.account.policyConfig.fetch(
+      policyConfigPda
+    );
+    expect(policyConfig.paused).to.be.true;
+
+    await ctx.program.methods
+      .unpause()
+      .accounts({
+        policyConfig: policyConfigPda,
+        authority: ctx.payer.publicKey,
+      })
+      .rpc();
+
+    policyConfig = await ctx.program.account.policyConfig.fetch(
+      policyConfigPda
+    );
+    expect(policyConfig.paused).to.be.false;
+  });
+
+  it("fails when a non-authority pauses", async () => {
+    const attacker = Keypair.generate();
+
+    try {
+      await ctx.program.methods
+        .pause()
+        .accounts({
+          policyConfig: policyConfigPda,
+          authority: attacker.publicKey,
+        })
+        .signers([attacker])
+        .rpc();
+
+      expect.fail("Should have failed with Unauthorized");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("Unauthorized");
+    }
+  });
+
+  it("lets the guardian pause all vaults", async () => {
+    const [guardianConfigPda] = deriveGuardianConfigPda(ctx.program);
+    const [programDataPda] = PublicKey.findProgramAddressSync(
+      [ctx.program.programId.toBuffer()],
+      BPF_LOADER_UPGRADEABLE_ID
+    );
+
+    // The local validator deploys with the provider wallet as upgrade authority
+    await ctx.program.methods
+      .setGuardian(ctx.payer.publicKey)
+      .accounts({
+        guardianConfig: guardianConfigPda,
+        program: ctx.program.programId,
+        programData: programDataPda,
+        upgradeAuthority: ctx.payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    await ctx.program.methods
+      .pauseAll()
+      .accounts({
+        guardianConfig: guardianConfigPda,
+        guardian: ctx.payer.publicKey,
+      })
+      .rpc();
+
+    let guardianConfig = await ctx.program.account.guardianConfig.fetch(
+      guardianConfigPda
+    );
+    expect(guardianConfig.paused).to.be.true;
+
+    await ctx.program.methods
+      .unpauseAll()
+      .accounts({
+        guardianConfig: guardianConfigPda,
+        guardian: ctx.payer.publicKey,
+      })
+      .rpc();
+
+    guardianConfig = await ctx.program.account.guardianConfig.fetch(
+      guardianConfigPda
+    );
+    expect(guardianConfig.paused).to.be.false;
+  });
+});
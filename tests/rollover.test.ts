@@ -0,0 +1,184 @@
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram, Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import { BN } from "bn.js";
+import { getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  setupTestContext,
+  derivePolicyConfigPda,
+  deriveDailyProgressPda,
+  deriveInvestorFeePositionOwnerPda,
+  createTokenAccount,
+  mintTokensTo,
+  getTokenBalance,
+  registerInvestors,
+  requireHonoraryPosition,
+  snapshotLockedTotal,
+  crankDistribution,
+  TestContext,
+  TestInvestor,
+  ONE_SOL,
+} from "./test-helpers";
+
+describe("roll_over_undistributed", () => {
+  let ctx: TestContext;
+  let creatorQuoteAta: PublicKey;
+  let policyConfigPda: PublicKey;
+  let dailyProgressPda: PublicKey;
+  let positionOwnerPda: PublicKey;
+  let registryPagePda: PublicKey;
+  let investors: TestInvestor[];
+
+  const DAILY_CAP = 10 * ONE_SOL;
+  const DEPOSITS = [100_000 * ONE_SOL, 200_000 * ONE_SOL, 300_000 * ONE_SOL];
+
+  before(async () => {
+    ctx = await setupTestContext();
+
+    creatorQuoteAta = await createTokenAccount(
+      ctx.provider,
+      ctx.quoteMint,
+      ctx.payer.publicKey
+    );
+
+    [policyConfigPda] = derivePolicyConfigPda(ctx.program, ctx.vault);
+    [dailyProgressPda] = deriveDailyProgressPda(ctx.program, ctx.vault);
+    [positionOwnerPda] = deriveInvestorFeePositionOwnerPda(
+      ctx.program,
+      ctx.vault
+    );
+
+    // Y0 equals the deposits, so nearly everything is locked and investors
+    // are owed the full 70% share
+    await ctx.program.methods
+      .initializePolicy(
+        ctx.vault,
+        7000,
+        new BN(DAILY_CAP),
+        new BN(1000),
+        new BN(DEPOSITS.reduce((sum, amount) => sum + amount, 0)),
+        creatorQuoteAta
+      )
+      .accounts({
+        policyConfig: policyConfigPda,
+        payer: ctx.payer.publicKey,
+        authority: ctx.payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    ({ investors, registryPage: registryPagePda } = await registerInvestors(
+      ctx,
+      policyConfigPda,
+      DEPOSITS
+    ));
+  });
+
+  it("toggles rolling undistributed investor share into the next day", async () => {
+    let policyConfig = await ctx.program.account.policyConfig.fetch(
+      policyConfigPda
+    );
+    expect(policyConfig.rollOverUndistributed).to.be.false;
+
+    await ctx.program.methods
+      .setRollOverUndistributed(true)
+      .accounts({
+        policyConfig: policyConfigPda,
+        dailyProgress: dailyProgressPda,
+        authority: ctx.payer.publicKey,
+      })
+      .rpc();
+
+    policyConfig = await ctx.program.account.policyConfig.fetch(
+      policyConfigPda
+    );
+    expect(policyConfig.rollOverUndistributed).to.be.true;
+  });
+
+  it("fails when a non-authority toggles rollover", async () => {
+    const attacker = Keypair.generate();
+
+    try {
+      await ctx.program.methods
+        .setRollOverUndistributed(false)
+        .accounts({
+          policyConfig: policyConfigPda,
+          dailyProgress: dailyProgressPda,
+          authority: attacker.publicKey,
+        })
+        .signers([attacker])
+        .rpc();
+
+      expect.fail("Should have failed with Unauthorized");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("Unauthorized");
+    }
+  });
+
+  it("rolls the share clipped by the daily cap into the next day", async function () {
+    await requireHonoraryPosition(this, ctx, positionOwnerPda);
+
+    const treasuryQuoteAta = await getAssociatedTokenAddress(
+      ctx.quoteMint,
+      positionOwnerPda,
+      true
+    );
+    const treasuryBaseAta = await getAssociatedTokenAddress(
+      ctx.baseMint,
+      positionOwnerPda,
+      true
+    );
+
+    const fees = 100 * ONE_SOL;
+    await mintTokensTo(ctx.provider, ctx.quoteMint, treasuryQuoteAta, fees);
+    const creatorBefore = await getTokenBalance(ctx.provider, creatorQuoteAta);
+
+    const streams = investors.map((inv) => inv.stream);
+    await snapshotLockedTotal(
+      ctx,
+      policyConfigPda,
+      dailyProgressPda,
+      positionOwnerPda,
+      registryPagePda,
+      streams
+    );
+    await crankDistribution(
+      ctx,
+      {
+        policyConfig: policyConfigPda,
+        dailyProgress: dailyProgressPda,
+        investorFeePositionOwner: positionOwnerPda,
+        investorRegistryPage: registryPagePda,
+        treasuryQuoteAta,
+        treasuryBaseAta,
+        creatorQuoteAta,
+      },
+      investors
+    );
+
+    // Investors are owed 70 tokens but the cap lets only 10 through today
+    const progress = await ctx.program.account.dailyProgress.fetch(
+      dailyProgressPda
+    );
+    expect(progress.isFinalized).to.be.true;
+    expect(progress.investorFeeQuote.toNumber()).to.equal(DAILY_CAP);
+
+    let paid = 0n;
+    for (const investor of investors) {
+      paid += await getTokenBalance(ctx.provider, investor.quoteAta);
+    }
+    expect(paid <= BigInt(DAILY_CAP)).to.be.true;
+
+    // The clipped 60 tokens and the cap's rounding stay for tomorrow's investors
+    expect(BigInt(progress.rolloverLamports.toString())).to.equal(
+      BigInt(70 * ONE_SOL) - paid
+    );
+
+    // ...so the creator receives only its own 30%
+    const creatorAfter = await getTokenBalance(ctx.provider, creatorQuoteAta);
+    expect(creatorAfter - creatorBefore).to.equal(BigInt(30 * ONE_SOL));
+    expect(await getTokenBalance(ctx.provider, treasuryQuoteAta)).to.equal(
+      BigInt(progress.rolloverLamports.toString())
+    );
+  });
+});
@@ -107,6 +107,38 @@ export function deriveDailyProgressPda(
   );
 }
 
+export function deriveGuardianConfigPda(
+  program: Program<InvestorFeeDistributor>
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("guardian_config")],
+    program.programId
+  );
+}
+
+export function deriveNativeUnwrapPda(
+  program: Program<InvestorFeeDistributor>,
+  vault: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("native_unwrap"), vault.toBuffer()],
+    program.programId
+  );
+}
+
+export function deriveInvestorRegistryPagePda(
+  program: Program<InvestorFeeDistributor>,
+  vault: PublicKey,
+  pageIndex: number
+): [PublicKey, number] {
+  const pageIndexBytes = Buffer.alloc(2);
+  pageIndexBytes.writeUInt16LE(pageIndex);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("investor_registry"), vault.toBuffer(), pageIndexBytes],
+    program.programId
+  );
+}
+
 export async function createTokenAccount(
   provider: anchor.AnchorProvider,
   mint: PublicKey,
@@ -182,7 +214,7 @@ export async function createMockStreamflowStream(
 
   // In real implementation, this would be created by Streamflow program
   // For testing, we'll create a mock account with similar structure
-  const space = 512; // Enough space for StreamflowStream struct
+  const space = 1104; // Streamflow stream account size
   const rent = await provider.connection.getMinimumBalanceForRentExemption(space);
 
   const payer = (provider.wallet as anchor.Wallet).payer;
@@ -224,3 +256,228 @@ export function sleep(ms: number): Promise<void> {
 export const ONE_SOL = 1_000_000_000;
 export const ONE_HOUR = 3600;
 export const ONE_DAY = 86400;
+
+export async function setInvestorRegistryPage(
+  ctx: TestContext,
+  policyConfig: PublicKey,
+  pageIndex: number,
+  entries: { stream: PublicKey; quoteAta: PublicKey }[],
+  // Needed when removed investors carry dust: it is paid to their quote ATAs
+  dustPayout?: {
+    positionOwner: PublicKey;
+    treasuryQuoteAta: PublicKey;
+    payoutAccounts: PublicKey[];
+  }
+): Promise<PublicKey> {
+  const [investorRegistryPage] = deriveInvestorRegistryPagePda(
+    ctx.program,
+    ctx.vault,
+    pageIndex
+  );
+
+  const [dailyProgress] = deriveDailyProgressPda(ctx.program, ctx.vault);
+
+  await ctx.program.methods
+    .setInvestorRegistryPage(pageIndex, entries)
+    .accounts({
+      policyConfig,
+      investorRegistryPage,
+      dailyProgress,
+      investorFeePositionOwner: dustPayout ? dustPayout.positionOwner : null,
+      quoteMint: dustPayout ? ctx.quoteMint : null,
+      treasuryQuoteAta: dustPayout ? dustPayout.treasuryQuoteAta : null,
+      tokenProgram: dustPayout ? TOKEN_PROGRAM_ID : null,
+      payer: ctx.payer.publicKey,
+      authority: ctx.payer.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .remainingAccounts(
+      (dustPayout ? dustPayout.payoutAccounts : []).map((pubkey) => ({
+        pubkey,
+        isSigner: false,
+        isWritable: true,
+      }))
+    )
+    .rpc();
+
+  return investorRegistryPage;
+}
+
+export async function setVestingFilter(
+  ctx: TestContext,
+  policyConfig: PublicKey,
+  vestingMint: PublicKey,
+  allowedSenders: PublicKey[],
+  vestingPrograms: { kind: object; programId: PublicKey }[] = [
+    { kind: { streamflow: {} }, programId: STREAMFLOW_PROGRAM_ID },
+  ]
+): Promise<string> {
+  const [dailyProgress] = deriveDailyProgressPda(ctx.program, ctx.vault);
+
+  return await ctx.program.methods
+    .setVestingFilter(vestingMint, allowedSenders, vestingPrograms)
+    .accounts({
+      policyConfig,
+      dailyProgress,
+      authority: ctx.payer.publicKey,
+    })
+    .rpc();
+}
+
+export async function snapshotLockedTotal(
+  ctx: TestContext,
+  policyConfig: PublicKey,
+  dailyProgress: PublicKey,
+  investorFeePositionOwner: PublicKey,
+  investorRegistryPage: PublicKey,
+  streams: PublicKey[]
+): Promise<string> {
+  return await ctx.program.methods
+    .snapshotLockedTotal()
+    .accounts({
+      cranker: ctx.payer.publicKey,
+      dailyProgress,
+      policyConfig,
+      investorFeePositionOwner,
+      investorRegistryPage,
+      previousRegistryPage: null,
+      weightTable: null,
+      guardianConfig: deriveGuardianConfigPda(ctx.program)[0],
+      systemProgram: SystemProgram.programId,
+    })
+    .remainingAccounts(
+      streams.map((pubkey) => ({
+        pubkey,
+        isSigner: false,
+        isWritable: false,
+      }))
+    )
+    .rpc();
+}
+
+// Cranks, sweeps and swaps need the vault's honorary position, which can only
+// be opened on a validator with the DAMM programs and a pool; tests relying on
+// it skip when the position owner does not exist
+export async function requireHonoraryPosition(
+  test: Mocha.Context,
+  ctx: TestContext,
+  positionOwner: PublicKey
+): Promise<void> {
+  const account = await ctx.provider.connection.getAccountInfo(positionOwner);
+  if (!account) {
+    console.log("Requires an honorary position on a DAMM pool - skipping");
+    test.skip();
+  }
+}
+
+export interface TestInvestor {
+  keypair: Keypair;
+  stream: PublicKey;
+  quoteAta: PublicKey;
+  depositedAmount: number;
+}
+
+// Create one-year Streamflow streams and quote ATAs for `deposits`, register
+// them on registry page 0 and accept streams vesting the base mint from the payer
+export async function registerInvestors(
+  ctx: TestContext,
+  policyConfig: PublicKey,
+  deposits: number[]
+): Promise<{ investors: TestInvestor[]; registryPage: PublicKey }> {
+  const investors: TestInvestor[] = [];
+
+  for (const depositedAmount of deposits) {
+    const keypair = Keypair.generate();
+    const stream = await createMockStreamflowStream(
+      ctx.provider,
+      keypair.publicKey,
+      depositedAmount,
+      365 * ONE_DAY
+    );
+    const quoteAta = await createTokenAccount(
+      ctx.provider,
+      ctx.quoteMint,
+      keypair.publicKey
+    );
+
+    investors.push({
+      keypair,
+      stream: stream.keypair.publicKey,
+      quoteAta,
+      depositedAmount,
+    });
+  }
+
+  // Registry pages list streams in ascending order
+  investors.sort((a, b) => Buffer.compare(a.stream.toBuffer(), b.stream.toBuffer()));
+
+  const registryPage = await setInvestorRegistryPage(
+    ctx,
+    policyConfig,
+    0,
+    investors.map((inv) => ({ stream: inv.stream, quoteAta: inv.quoteAta }))
+  );
+  await setVestingFilter(ctx, policyConfig, ctx.baseMint, [
+    ctx.payer.publicKey,
+  ]);
+
+  return { investors, registryPage };
+}
+
+export interface CrankAccounts {
+  policyConfig: PublicKey;
+  dailyProgress: PublicKey;
+  investorFeePositionOwner: PublicKey;
+  investorRegistryPage: PublicKey;
+  treasuryQuoteAta: PublicKey;
+  treasuryBaseAta: PublicKey;
+  creatorQuoteAta: PublicKey;
+  // Native SOL payouts only
+  nativeUnwrapAccount?: PublicKey;
+  creatorWallet?: PublicKey;
+}
+
+// Pay one registry page of `investors` (in registry order); payouts go to
+// their quote ATAs unless `payoutAccounts` names others (recipient wallets
+// when paying native SOL)
+export async function crankDistribution(
+  ctx: TestContext,
+  accounts: CrankAccounts,
+  investors: { stream: PublicKey; quoteAta: PublicKey }[],
+  payoutAccounts: PublicKey[] = investors.map((inv) => inv.quoteAta)
+): Promise<string> {
+  return await ctx.program.methods
+    .crankDistribution(
+      investors.map((inv, index) => ({ index, quoteAta: inv.quoteAta }))
+    )
+    .accounts({
+      cranker: ctx.payer.publicKey,
+      dailyProgress: accounts.dailyProgress,
+      policyConfig: accounts.policyConfig,
+      guardianConfig: deriveGuardianConfigPda(ctx.program)[0],
+      investorFeePositionOwner: accounts.investorFeePositionOwner,
+      investorRegistryPage: accounts.investorRegistryPage,
+      weightTable: null,
+      quoteMint: ctx.quoteMint,
+      treasuryQuoteAta: accounts.treasuryQuoteAta,
+      treasuryBaseAta: accounts.treasuryBaseAta,
+      creatorQuoteAta: accounts.creatorQuoteAta,
+      nativeUnwrapAccount: accounts.nativeUnwrapAccount ?? null,
+      creatorWallet: accounts.creatorWallet ?? null,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .remainingAccounts([
+      ...investors.map((inv) => ({
+        pubkey: inv.stream,
+        isSigner: false,
+        isWritable: false,
+      })),
+      ...payoutAccounts.map((pubkey) => ({
+        pubkey,
+        isSigner: false,
+        isWritable: true,
+      })),
+    ])
+    .rpc();
+}
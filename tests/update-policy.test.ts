@@ -0,0 +1,158 @@
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram, Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import { BN } from "bn.js";
+import {
+  setupTestContext,
+  derivePolicyConfigPda,
+  deriveDailyProgressPda,
+  deriveInvestorFeePositionOwnerPda,
+  createTokenAccount,
+  TestContext,
+  ONE_SOL,
+} from "./test-helpers";
+
+describe("update_policy", () => {
+  let ctx: TestContext;
+  let creatorQuoteAta: PublicKey;
+  let policyConfigPda: PublicKey;
+  let dailyProgressPda: PublicKey;
+  let positionOwnerPda: PublicKey;
+
+  before(async () => {
+    ctx = await setupTestContext();
+
+    creatorQuoteAta = await createTokenAccount(
+      ctx.provider,
+      ctx.quoteMint,
+      ctx.payer.publicKey
+    );
+
+    [policyConfigPda] = derivePolicyConfigPda(ctx.program, ctx.vault);
+    [dailyProgressPda] = deriveDailyProgressPda(ctx.program, ctx.vault);
+    [positionOwnerPda] = deriveInvestorFeePositionOwnerPda(
+      ctx.program,
+      ctx.vault
+    );
+
+    await ctx.program.methods
+      .initializePolicy(
+        ctx.vault,
+        7000,
+        new BN(10 * ONE_SOL),
+        new BN(1000),
+        new BN(1_000_000 * ONE_SOL),
+        creatorQuoteAta
+      )
+      .accounts({
+        policyConfig: policyConfigPda,
+        payer: ctx.payer.publicKey,
+        authority: ctx.payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  });
+
+  it("updates policy parameters when signed by the authority", async () => {
+    const newCreatorQuoteAta = await createTokenAccount(
+      ctx.provider,
+      ctx.quoteMint,
+      ctx.payer.publicKey
+    );
+
+    await ctx.program.methods
+      .updatePolicy(
+        5000,
+        null,
+        new BN(2000),
+        new BN(2_000_000 * ONE_SOL),
+        newCreatorQuoteAta
+      )
+      .accounts({
+        policyConfig: policyConfigPda,
+        dailyProgress: dailyProgressPda,
+        authority: ctx.payer.publicKey,
+      })
+      .rpc();
+
+    const policyConfig = await ctx.program.account.policyConfig.fetch(
+      policyConfigPda
+    );
+
+    expect(policyConfig.investorFeeShareBps).to.equal(5000);
+    expect(policyConfig.dailyCapLamports).to.be.null;
+    expect(policyConfig.minPayoutLamports.toString()).to.equal("2000");
+    expect(policyConfig.y0TotalStreamed.toString()).to.equal(
+      new BN(2_000_000 * ONE_SOL).toString()
+    );
+    expect(policyConfig.creatorQuoteAta.toString()).to.equal(
+      newCreatorQuoteAta.toString()
+    );
+  });
+
+  it("fails when signed by a non-authority", async () => {
+    const attacker = Keypair.generate();
+
+    try {
+      await ctx.program.methods
+        .updatePolicy(
+          10000,
+          null,
+          new BN(0),
+          new BN(1),
+          attacker.publicKey
+        )
+        .accounts({
+          policyConfig: policyConfigPda,
+          dailyProgress: dailyProgressPda,
+          authority: attacker.publicKey,
+        })
+        .signers([attacker])
+        .rpc();
+
+      expect.fail("Should have failed with Unauthorized");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("Unauthorized");
+    }
+  });
+
+  it("fails when investor_fee_share_bps exceeds 10000", async () => {
+    try {
+      await ctx.program.methods
+        .updatePolicy(
+          10001,
+          null,
+          new BN(1000),
+          new BN(1_000_000 * ONE_SOL),
+          creatorQuoteAta
+        )
+        .accounts({
+          policyConfig: policyConfigPda,
+          dailyProgress: dailyProgressPda,
+          authority: ctx.payer.publicKey,
+        })
+        .rpc();
+
+      expect.fail("Should have failed with InvalidFeeShareBps");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidFeeShareBps");
+    }
+  });
+
+  it("fails when y0_total_streamed is zero", async () => {
+    try {
+      await ctx.program.methods
+        .updatePolicy(7000, null, new BN(1000), new BN(0), creatorQuoteAta)
+        .accounts({
+          policyConfig: policyConfigPda,
+          dailyProgress: dailyProgressPda,
+          authority: ctx.payer.publicKey,
+        })
+        .rpc();
+
+      expect.fail("Should have failed with InvalidY0Amount");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidY0Amount");
+    }
+  });
+});